@@ -30,6 +30,9 @@ const STATE_KEYPAIR = web3.Keypair.fromSecretKey(bs58.decode("5ShxpFnV9DiWPe84Wk
 
 const STATE_ID = STATE_KEYPAIR.publicKey;
 
+// Must match `STAKER_LEDGER_LEN` in the program.
+const STAKER_LEDGER_LEN = 192;
+
 function Content() {
   const { connection } = useConnection();
   const wallet = useWallet();
@@ -117,11 +120,27 @@ function Content() {
   const [balance, setBalance] = useState<number | undefined>();
 
   async function getAuthorityAddress(): Promise<[web3.PublicKey, number]> {
-    return web3.PublicKey.findProgramAddress([Buffer.from("authority")], PROGRAM_ID);
+    return web3.PublicKey.findProgramAddress([Buffer.from("authority"), STATE_ID.toBuffer()], PROGRAM_ID);
   }
 
   async function getTreasuryAddress(): Promise<[web3.PublicKey, number]> {
-    return web3.PublicKey.findProgramAddress([Buffer.from("treasury")], PROGRAM_ID);
+    return web3.PublicKey.findProgramAddress([Buffer.from("treasury"), STATE_ID.toBuffer()], PROGRAM_ID);
+  }
+
+  async function getPoolConfigAddress(): Promise<[web3.PublicKey, number]> {
+    return web3.PublicKey.findProgramAddress([Buffer.from("pool_config"), STATE_ID.toBuffer()], PROGRAM_ID);
+  }
+
+  async function getStakerAddress(): Promise<[web3.PublicKey, number]> {
+    return web3.PublicKey.findProgramAddress([Buffer.from("staker"), STATE_ID.toBuffer(), wallet.publicKey.toBuffer()], PROGRAM_ID);
+  }
+
+  async function getEscrowAddress(mintId: web3.PublicKey): Promise<[web3.PublicKey, number]> {
+    return web3.PublicKey.findProgramAddress([Buffer.from("escrow"), STATE_ID.toBuffer(), mintId.toBuffer()], PROGRAM_ID);
+  }
+
+  async function getLockedNftAddress(mintId: web3.PublicKey): Promise<[web3.PublicKey, number]> {
+    return web3.PublicKey.findProgramAddress([Buffer.from("locked_nft"), STATE_ID.toBuffer(), mintId.toBuffer()], PROGRAM_ID);
   }
 
   useEffect(() => {
@@ -151,11 +170,13 @@ function Content() {
   const [authorityAddress, setAuthorityAddress] = useState<[web3.PublicKey, number] | undefined>();
   const [stakerAddress, setStakerAddress] = useState<[web3.PublicKey, number] | undefined>();
   const [treasuryAddress, setTreasuryAddress] = useState<[web3.PublicKey, number] | undefined>();
+  const [poolConfigAddress, setPoolConfigAddress] = useState<[web3.PublicKey, number] | undefined>();
 
   useEffect(() => {
     async function updateAddresses() {
       setAuthorityAddress(await getAuthorityAddress());
       setTreasuryAddress(await getTreasuryAddress());
+      setPoolConfigAddress(await getPoolConfigAddress());
     }
     updateAddresses();
   }, []);
@@ -167,7 +188,7 @@ function Content() {
     }
     let cancelled = false;
     async function updateStakerAddress() {
-      const stakerAddress = await web3.PublicKey.findProgramAddress([Buffer.from("staker"), wallet.publicKey.toBuffer()], PROGRAM_ID);
+      const stakerAddress = await getStakerAddress();
       if (cancelled) return;
       setStakerAddress(stakerAddress);
     }
@@ -263,13 +284,20 @@ function Content() {
     let cancelled = false;
 
     async function fetchLockedNfts() {
-      const stakedNftResults = await program.account.lockedNft.all([{
+      let stakedNftResults = await program.account.lockedNft.all([{
         memcmp: {
           offset: 8,
           bytes: bs58.encode(wallet.publicKey.toBuffer()),
         }
       }]);
       if (cancelled) return;
+
+      // Locked NFTs of every pool the wallet stakes in are fetched, so keep only those of this pool.
+
+      const lockedNftIds = await Promise.all(stakedNftResults.map(async stakedNftResult => (await getLockedNftAddress(stakedNftResult.account.mintId))[0]));
+      if (cancelled) return;
+      stakedNftResults = stakedNftResults.filter((stakedNftResult, i) => stakedNftResult.publicKey.equals(lockedNftIds[i]));
+
       const lockedNftMetadataIds = await Promise.all(stakedNftResults.map(async stakedNftResult => await Metadata.getPDA(stakedNftResult.account.mintId)));
       const lockedNftMetadataAccounts = await connection.getMultipleAccountsInfo(lockedNftMetadataIds);
       const lockedNfts = lockedNftMetadataAccounts.map((account, i) => account ? {
@@ -432,21 +460,22 @@ function Content() {
     const provider = new anchor.Provider(connection, wallet, { commitment: "confirmed" });
     const program = new anchor.Program<SolcietyStakingPool>(IDL, PROGRAM_ID, provider);
 
-    const [authorityId, authorityBump] = authorityAddress;
+    const [authorityId] = authorityAddress;
     const [treasuryId] = treasuryAddress;
 
     try {
       setClaimingRewards(true);
 
-      const [stakerId] = await web3.PublicKey.findProgramAddress([Buffer.from("staker"), wallet.publicKey.toBuffer()], PROGRAM_ID);
+      const [stakerId] = await getStakerAddress();
       const rewardAtaId = await spl.Token.getAssociatedTokenAddress(spl.ASSOCIATED_TOKEN_PROGRAM_ID, spl.TOKEN_PROGRAM_ID, CIETY_TOKEN_MINT_ID, wallet.publicKey);
 
       const tx = new web3.Transaction();
 
-      tx.add(program.instruction.claimRewards(authorityBump, {
+      tx.add(program.instruction.claimRewardsV2({
         accounts: {
           user: wallet.publicKey,
           state: STATE_ID,
+          rewardSchedule: state.rewardScheduleId,
           authority: authorityId,
           staker: stakerId,
           rewardTokenMint: CIETY_TOKEN_MINT_ID,
@@ -480,25 +509,26 @@ function Content() {
     const provider = new anchor.Provider(connection, wallet, { commitment: "confirmed" });
     const program = new anchor.Program<SolcietyStakingPool>(IDL, PROGRAM_ID, provider);
 
-    const [authorityId, authorityBump] = authorityAddress;
+    const [authorityId] = authorityAddress;
 
     try {
       setUnstakingNfts(true);
 
-      const [stakerId] = await web3.PublicKey.findProgramAddress([Buffer.from("staker"), wallet.publicKey.toBuffer()], PROGRAM_ID);
+      const [stakerId] = await getStakerAddress();
 
       const tx = new web3.Transaction();
 
       for (const rawMintId of Array.from(selectedLockedNfts.keys())) {
         const mintId = new web3.PublicKey(rawMintId);
         const nftId = await spl.Token.getAssociatedTokenAddress(spl.ASSOCIATED_TOKEN_PROGRAM_ID, spl.TOKEN_PROGRAM_ID, mintId, wallet.publicKey);
-        const [escrowId] = await web3.PublicKey.findProgramAddress([Buffer.from("escrow"), mintId.toBuffer()], PROGRAM_ID);
-        const [lockedNftId] = await web3.PublicKey.findProgramAddress([Buffer.from("locked_nft"), mintId.toBuffer()], PROGRAM_ID);
+        const [escrowId] = await getEscrowAddress(mintId);
+        const [lockedNftId] = await getLockedNftAddress(mintId);
 
-        tx.add(program.instruction.unstake(authorityBump, {
+        tx.add(program.instruction.unstakeV2({
           accounts: {
             user: wallet.publicKey,
             state: STATE_ID,
+            rewardSchedule: state.rewardScheduleId,
             authority: authorityId,
             nftMint: mintId,
             nft: nftId,
@@ -538,18 +568,19 @@ function Content() {
     try {
       setExtendingNfts(true);
 
-      const [stakerId] = await web3.PublicKey.findProgramAddress([Buffer.from("staker"), wallet.publicKey.toBuffer()], PROGRAM_ID);
+      const [stakerId] = await getStakerAddress();
 
       const tx = new web3.Transaction();
 
       for (const rawMintId of Array.from(selectedLockedNfts.keys())) {
         const mintId = new web3.PublicKey(rawMintId);
-        const [lockedNftId] = await web3.PublicKey.findProgramAddress([Buffer.from("locked_nft"), mintId.toBuffer()], PROGRAM_ID);
+        const [lockedNftId] = await getLockedNftAddress(mintId);
 
         tx.add(program.instruction.extend(new anchor.BN(numDaysToExtend), {
           accounts: {
             user: wallet.publicKey,
             state: STATE_ID,
+            rewardSchedule: state.rewardScheduleId,
             authority: authorityId,
             nftMint: mintId,
             staker: stakerId,
@@ -580,11 +611,12 @@ function Content() {
     const program = new anchor.Program<SolcietyStakingPool>(IDL, PROGRAM_ID, provider);
 
     const [authorityId] = authorityAddress;
+    const [poolConfigId] = poolConfigAddress;
 
     try {
       setStakingNfts(true);
 
-      const [stakerId] = await web3.PublicKey.findProgramAddress([Buffer.from("staker"), wallet.publicKey.toBuffer()], PROGRAM_ID);
+      const [stakerId] = await getStakerAddress();
 
       const tx = new web3.Transaction();
 
@@ -592,14 +624,16 @@ function Content() {
         const mintId = new web3.PublicKey(rawMintId);
         const nftId = await spl.Token.getAssociatedTokenAddress(spl.ASSOCIATED_TOKEN_PROGRAM_ID, spl.TOKEN_PROGRAM_ID, mintId, wallet.publicKey);
         const metadataId = await Metadata.getPDA(mintId);
-        const [escrowId] = await web3.PublicKey.findProgramAddress([Buffer.from("escrow"), mintId.toBuffer()], PROGRAM_ID);
-        const [lockedNftId] = await web3.PublicKey.findProgramAddress([Buffer.from("locked_nft"), mintId.toBuffer()], PROGRAM_ID);
+        const [escrowId] = await getEscrowAddress(mintId);
+        const [lockedNftId] = await getLockedNftAddress(mintId);
 
         tx.add(program.instruction.stake(new anchor.BN(numDaysToStake), {
           accounts: {
             user: wallet.publicKey,
             state: STATE_ID,
+            rewardSchedule: state.rewardScheduleId,
             authority: authorityId,
+            poolConfig: poolConfigId,
             nft: nftId,
             nftMint: mintId,
             nftMetadata: metadataId,
@@ -631,24 +665,35 @@ function Content() {
 
     const [authorityId] = authorityAddress;
     const [treasuryId] = treasuryAddress;
+    const [poolConfigId] = poolConfigAddress;
 
     const provider = new anchor.Provider(connection, wallet, { commitment: "confirmed" });
     const program = new anchor.Program<SolcietyStakingPool>(IDL, PROGRAM_ID, provider);
 
-    await program.rpc.initialize({
-      preInstructions: [await program.account.state.createInstruction(STATE_KEYPAIR)],
+    // The reward schedule still has to be written and sealed by the admin before NFTs may be
+    // staked.
+
+    const rewardScheduleKeypair = web3.Keypair.generate();
+
+    await program.rpc.initialize(new anchor.BN(1), new anchor.BN(Math.floor(365 / 2)), {
+      preInstructions: [
+        await program.account.state.createInstruction(STATE_KEYPAIR),
+        await program.account.rewardSchedule.createInstruction(rewardScheduleKeypair),
+      ],
       accounts: {
         admin: wallet.publicKey,
         state: STATE_ID,
+        rewardSchedule: rewardScheduleKeypair.publicKey,
         authority: authorityId,
         rewardTokenMint: CIETY_TOKEN_MINT_ID,
         rewardTokenTreasury: treasuryId,
+        poolConfig: poolConfigId,
         rent: web3.SYSVAR_RENT_PUBKEY,
         tokenProgram: spl.TOKEN_PROGRAM_ID,
         associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: web3.SystemProgram.programId,
       },
-      signers: [STATE_KEYPAIR],
+      signers: [STATE_KEYPAIR, rewardScheduleKeypair],
     });
   }
 
@@ -671,12 +716,35 @@ function Content() {
     return getDaysElapsedBetweenTimestamps(+staker.lastUpdatedAt.toString(), Math.floor(currentTime / 1000));
   }
 
+  // Mirrors `Staker::venft_balance_on_day` in the program, including veNFT's held for perpetually
+  // locked NFTs. `day` is counted from the day the program was deployed.
+  function getStakerVenftBalanceOnDay(day: number): Decimal {
+    const lastDay = Math.floor(getDaysElapsedBetweenTimestamps(+state.deployedAt.toString(), +staker.lastUpdatedAt.toString()));
+    const perpetualVenftBalance = new Decimal(staker.perpetualVenftBalance.toString());
+    if (day < lastDay) return perpetualVenftBalance;
+
+    let balance = new Decimal(staker.venftBalance.toString());
+    let change = new Decimal(staker.venftBalanceChange.toString());
+    for (let i = lastDay + 1; i <= day; i++) {
+      if (i < lastDay + STAKER_LEDGER_LEN) {
+        change = change.add(staker.venftBalanceChangeDeltas[i % STAKER_LEDGER_LEN].toString());
+      }
+      balance = balance.add(change);
+    }
+    return Decimal.max(balance, 0).add(perpetualVenftBalance);
+  }
+
+  function getTotalVenftSupplyOnDay(day: number): Decimal {
+    const venftSupply = new Decimal(day < state.venftSupply.length ? state.venftSupply[day].toString() : 0);
+    return venftSupply.add(state.perpetualVenftSupply.toString());
+  }
+
   function getEstimatedStakerVenftBalance(): Decimal | undefined {
-    if (!staker) return undefined;
+    if (!state || !staker) return undefined;
     const secondsElapsedInDay = Math.floor(currentTime / 1000) % (24 * 60 * 60);
-    const dayIndex = Math.floor(getDaysElapsedSinceStakerLastUpdated());
-    const currentVenftBalance = new Decimal(dayIndex < staker.venftBalance.length ? staker.venftBalance[dayIndex].toString() : 0);
-    const nextVenftBalance = new Decimal(dayIndex + 1 < staker.venftBalance.length ? staker.venftBalance[dayIndex + 1].toString() : 0);
+    const dayIndex = Math.floor(getDaysElapsedSinceProgramDeployed());
+    const currentVenftBalance = getStakerVenftBalanceOnDay(dayIndex);
+    const nextVenftBalance = getStakerVenftBalanceOnDay(dayIndex + 1);
     const balance = currentVenftBalance.add(nextVenftBalance.sub(currentVenftBalance).mul(secondsElapsedInDay / (24 * 60 * 60)));
     return balance.div(1_000_000_000).div(Math.floor(365 / 2));
   }
@@ -696,9 +764,11 @@ function Content() {
     const stateOffset = Math.floor(getDaysElapsedBetweenTimestamps(+state.deployedAt.toString(), +staker.lastUpdatedAt.toString()));
     const stakerOffset = Math.floor(getDaysElapsedSinceStakerLastUpdated());
 
-    let accumulated = new Decimal(staker.numRewardsClaimable.toString());
-    for (let i = 0; i < Math.min(stakerOffset, staker.venftBalance.length); i++) {
-      accumulated = accumulated.add(new Decimal(REWARD_SCHEDULE[i]).mul(staker.venftBalance[i]).div(state.venftSupply[stateOffset + i]));
+    let accumulated = new Decimal(staker.numRewardsClaimable.toString()).div(1_000_000_000);
+    for (let day = stateOffset; day < Math.min(stateOffset + stakerOffset, REWARD_SCHEDULE.length); day++) {
+      const totalVenftSupply = getTotalVenftSupplyOnDay(day);
+      if (totalVenftSupply.isZero()) continue;
+      accumulated = accumulated.add(new Decimal(REWARD_SCHEDULE[day]).mul(getStakerVenftBalanceOnDay(day)).div(totalVenftSupply));
     }
 
     return accumulated;
@@ -714,9 +784,11 @@ function Content() {
     if (!state || !staker) return undefined;
     const stateOffset = Math.floor(getDaysElapsedBetweenTimestamps(+state.deployedAt.toString(), +staker.lastUpdatedAt.toString()));
     const stakerOffset = Math.floor(getDaysElapsedSinceStakerLastUpdated());
-    if (stateOffset + stakerOffset >= state.venftSupply.length) return new Decimal(0);
-    if (stakerOffset >= staker.venftBalance.length) return new Decimal(0);
-    return new Decimal(REWARD_SCHEDULE[stateOffset + stakerOffset]).mul(staker.venftBalance[stakerOffset].toString()).div(state.venftSupply[stateOffset + stakerOffset].toString());
+    const day = stateOffset + stakerOffset;
+    if (day >= REWARD_SCHEDULE.length) return new Decimal(0);
+    const totalVenftSupply = getTotalVenftSupplyOnDay(day);
+    if (totalVenftSupply.isZero()) return new Decimal(0);
+    return new Decimal(REWARD_SCHEDULE[day]).mul(getStakerVenftBalanceOnDay(day)).div(totalVenftSupply);
   }
 
   return (
@@ -794,7 +866,7 @@ function Content() {
 
           <div>
             <h3 className="font-semibold">Estimated veNFT Tokens Owned</h3>
-            <p className="overflow-hidden text-ellipsis">{staker ? `${getEstimatedStakerVenftBalance().toFixed(9)} veNFT` : "Never staked before."}</p>
+            <p className="overflow-hidden text-ellipsis">{state && staker ? `${getEstimatedStakerVenftBalance().toFixed(9)} veNFT` : "Never staked before."}</p>
           </div>
 
           <div>
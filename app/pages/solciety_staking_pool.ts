@@ -15,6 +15,11 @@ export type SolcietyStakingPool = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "rewardSchedule",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": true,
@@ -30,6 +35,11 @@ export type SolcietyStakingPool = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "rent",
           "isMut": false,
@@ -51,69 +61,137 @@ export type SolcietyStakingPool = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "minLockDurationInDays",
+          "type": "u64"
+        },
+        {
+          "name": "maxLockDurationInDays",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "stake",
+      "name": "proposeAdmin",
       "accounts": [
         {
-          "name": "user",
-          "isMut": true,
+          "name": "admin",
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "state",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "newAdminId",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdmin",
+      "accounts": [
         {
-          "name": "nftMint",
+          "name": "newAdmin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "nftMetadata",
-          "isMut": false,
+          "name": "state",
+          "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setLockDurationBounds",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "nft",
+          "name": "state",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "minLockDurationInDays",
+          "type": "u64"
         },
         {
-          "name": "nftEscrow",
-          "isMut": true,
-          "isSigner": false
+          "name": "maxLockDurationInDays",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "staker",
+          "name": "state",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pausedInstructions",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setAdminActionDelay",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "lockedNft",
+          "name": "state",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "adminActionDelayInSeconds",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "queueAdminAction",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "state",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
+          "name": "queuedAdminAction",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -124,373 +202,264 @@ export type SolcietyStakingPool = {
       ],
       "args": [
         {
-          "name": "lockDurationInDays",
+          "name": "actionId",
           "type": "u64"
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          }
         }
       ]
     },
     {
-      "name": "claimRewards",
+      "name": "cancelAdminAction",
       "accounts": [
         {
-          "name": "user",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
           "name": "state",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "queuedAdminAction",
+          "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeAdminAction",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "staker",
+          "name": "state",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardTokenMint",
-          "isMut": false,
+          "name": "poolConfig",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardToken",
+          "name": "rewardSchedule",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardTokenTreasury",
+          "name": "queuedAdminAction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "rewardTokenTreasury",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
+          "name": "destination",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "authorityBump",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "extend",
+      "name": "createMultisig",
       "accounts": [
         {
-          "name": "user",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "state",
+          "name": "multisig",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "multisigSigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "signers",
+          "type": {
+            "vec": "publicKey"
+          }
         },
         {
-          "name": "staker",
+          "name": "threshold",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setMultisigSigners",
+      "accounts": [
+        {
+          "name": "multisig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lockedNft",
-          "isMut": true,
-          "isSigner": false
+          "name": "multisigSigner",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "lockDurationInDays",
+          "name": "signers",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "unstake",
+      "name": "proposeMultisigTransaction",
       "accounts": [
         {
-          "name": "user",
+          "name": "proposer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "state",
+          "name": "multisig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "multisigTransaction",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "transactionId",
+          "type": "u64"
         },
         {
-          "name": "nft",
-          "isMut": true,
-          "isSigner": false
+          "name": "instructionProgramId",
+          "type": "publicKey"
         },
         {
-          "name": "nftEscrow",
-          "isMut": true,
-          "isSigner": false
+          "name": "instructionAccounts",
+          "type": {
+            "vec": {
+              "defined": "MultisigTransactionAccount"
+            }
+          }
         },
         {
-          "name": "staker",
-          "isMut": true,
+          "name": "instructionData",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "approveMultisigTransaction",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "multisig",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lockedNft",
+          "name": "multisigTransaction",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeMultisigTransaction",
+      "accounts": [
         {
-          "name": "tokenProgram",
+          "name": "multisig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "multisigSigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "multisigTransaction",
+          "isMut": true,
           "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "sunset",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
         {
-          "name": "authorityBump",
-          "type": "u8"
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
         }
-      ]
-    }
-  ],
-  "accounts": [
+      ],
+      "args": []
+    },
     {
-      "name": "authority",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "stateId",
-            "type": "publicKey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "lockedNft",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "stakerId",
-            "type": "publicKey"
-          },
-          {
-            "name": "mintId",
-            "type": "publicKey"
-          },
-          {
-            "name": "lockedAt",
-            "type": "i64"
-          },
-          {
-            "name": "lockDurationInDays",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "state",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "adminId",
-            "type": "publicKey"
-          },
-          {
-            "name": "rewardTokenMintId",
-            "type": "publicKey"
-          },
-          {
-            "name": "deployedAt",
-            "type": "i64"
-          },
-          {
-            "name": "lastUpdatedAt",
-            "type": "i64"
-          },
-          {
-            "name": "totalNumLockedNfts",
-            "type": "u64"
-          },
-          {
-            "name": "venftSupply",
-            "type": {
-              "array": [
-                "u64",
-                1461
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "staker",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "stakerId",
-            "type": "publicKey"
-          },
-          {
-            "name": "venftBalance",
-            "type": {
-              "array": [
-                "u64",
-                183
-              ]
-            }
-          },
-          {
-            "name": "numLockedNfts",
-            "type": "u64"
-          },
-          {
-            "name": "numRewardsClaimable",
-            "type": "u64"
-          },
-          {
-            "name": "lastUpdatedAt",
-            "type": "i64"
-          },
-          {
-            "name": "lastClaimedAt",
-            "type": "i64"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "ErrorCode",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "UnexpectedRewardTokenMintId"
-          },
-          {
-            "name": "UnexpectedRewardTokenMintDecimals"
-          },
-          {
-            "name": "MetadataMintMismatch"
-          },
-          {
-            "name": "MetadataHasNoCreators"
-          },
-          {
-            "name": "MetadataCreatorUnverified"
-          },
-          {
-            "name": "UnexpectedMetadataCreator"
-          },
-          {
-            "name": "StakerIdMismatch"
-          },
-          {
-            "name": "LockDurationTooSmall"
-          },
-          {
-            "name": "MaxPossibleLockDurationExceeded"
-          },
-          {
-            "name": "NotYetUnlockable"
-          }
-        ]
-      }
-    },
-    {
-      "name": "AccountLoaderStatus",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Uninitialized",
-            "fields": [
-              {
-                "defined": "RefMut<'info,T>"
-              }
-            ]
-          },
-          {
-            "name": "Initialized",
-            "fields": [
-              {
-                "defined": "RefMut<'info,T>"
-              }
-            ]
-          }
-        ]
-      }
-    }
-  ]
-};
-
-export const IDL: SolcietyStakingPool = {
-  "version": "0.1.0",
-  "name": "solciety_staking_pool",
-  "instructions": [
-    {
-      "name": "initialize",
+      "name": "setZeroSupplyRewardsPolicy",
       "accounts": [
         {
           "name": "admin",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -499,49 +468,49 @@ export const IDL: SolcietyStakingPool = {
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardTokenMint",
+          "name": "rewardSchedule",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "rewardTokenTreasury",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "zeroSupplyRewardsPolicy",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setEarlyUnstakePenalty",
+      "accounts": [
         {
-          "name": "rent",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "state",
+          "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "earlyUnstakePenaltyBps",
+          "type": "u64"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "earlyUnstakePenaltyDestination",
+          "type": "u64"
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "stake",
+      "name": "reclaimRewards",
       "accounts": [
         {
-          "name": "user",
-          "isMut": true,
+          "name": "admin",
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -550,136 +519,309 @@ export const IDL: SolcietyStakingPool = {
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "nftMint",
+          "name": "rewardSchedule",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "nftMetadata",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "nft",
+          "name": "rewardTokenTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftEscrow",
+          "name": "destination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "staker",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalize",
+      "accounts": [
         {
-          "name": "lockedNft",
+          "name": "state",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "rewardSchedule",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sweepTreasury",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addAllowedCreator",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "lockDurationInDays",
-          "type": "u64"
+          "name": "address",
+          "type": "publicKey"
+        },
+        {
+          "name": "mustBeVerified",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "claimRewards",
+      "name": "removeAllowedCreator",
       "accounts": [
         {
-          "name": "user",
-          "isMut": true,
+          "name": "admin",
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addAllowedCollection",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "staker",
+          "name": "poolConfig",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collectionMintId",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeAllowedCollection",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "rewardTokenMint",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rewardToken",
+          "name": "poolConfig",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collectionMintId",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "writeRewardSchedule",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "rewardTokenTreasury",
+          "name": "rewardSchedule",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "dayOffset",
+          "type": "u64"
         },
         {
-          "name": "rent",
+          "name": "rewardPerDay",
+          "type": {
+            "vec": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setEmissionCurve",
+      "accounts": [
+        {
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "rewardSchedule",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "emissionCurve",
+          "type": {
+            "defined": "EmissionCurve"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sealRewardSchedule",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rewardSchedule",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "totalRewardEmission",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setNftGatingMode",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "authorityBump",
-          "type": "u8"
+          "name": "nftGatingMode",
+          "type": {
+            "defined": "NftGatingMode"
+          }
         }
       ]
     },
     {
-      "name": "extend",
+      "name": "stake",
       "accounts": [
         {
           "name": "user",
@@ -691,8 +833,18 @@ export const IDL: SolcietyStakingPool = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
           "isMut": false,
           "isSigner": false
         },
@@ -701,6 +853,21 @@ export const IDL: SolcietyStakingPool = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "nftMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "staker",
           "isMut": true,
@@ -710,6 +877,26 @@ export const IDL: SolcietyStakingPool = {
           "name": "lockedNft",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -720,28 +907,48 @@ export const IDL: SolcietyStakingPool = {
       ]
     },
     {
-      "name": "unstake",
+      "name": "stakeFor",
       "accounts": [
         {
-          "name": "user",
+          "name": "depositor",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "beneficiary",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "state",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "poolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "nftMint",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "nftMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "nft",
           "isMut": true,
@@ -762,6 +969,11 @@ export const IDL: SolcietyStakingPool = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -780,76 +992,3817 @@ export const IDL: SolcietyStakingPool = {
       ],
       "args": [
         {
-          "name": "authorityBump",
-          "type": "u8"
+          "name": "lockDurationInDays",
+          "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "authority",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "stateId",
-            "type": "publicKey"
-          }
-        ]
-      }
     },
     {
-      "name": "lockedNft",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "stakerId",
-            "type": "publicKey"
-          },
-          {
-            "name": "mintId",
-            "type": "publicKey"
-          },
-          {
-            "name": "lockedAt",
-            "type": "i64"
-          },
+      "name": "stakeMany",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lockDurationsInDays",
+          "type": {
+            "vec": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimRewardsV2",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimAvailableRewards",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fundTreasury",
+      "accounts": [
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funderRewardToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "extend",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lockDurationInDays",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPerpetualLock",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "perpetual",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "extendAllToMax",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unstakeV2",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unstakeMany",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unstakeEarly",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "returnNft",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "stakerWallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "authority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stateId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "poolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stateId",
+            "type": "publicKey"
+          },
+          {
+            "name": "nftGatingMode",
+            "type": {
+              "defined": "NftGatingMode"
+            }
+          },
+          {
+            "name": "allowedCreators",
+            "type": {
+              "vec": {
+                "defined": "AllowedCreator"
+              }
+            }
+          },
+          {
+            "name": "allowedCollections",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "lockedNft",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stakerId",
+            "type": "publicKey"
+          },
+          {
+            "name": "mintId",
+            "type": "publicKey"
+          },
+          {
+            "name": "lockedAt",
+            "type": "i64"
+          },
+          {
+            "name": "lockDurationInDays",
+            "type": "u64"
+          },
+          {
+            "name": "venftStartDay",
+            "type": "u64"
+          },
+          {
+            "name": "venftStartBalance",
+            "type": "u64"
+          },
+          {
+            "name": "venftDecayingBalance",
+            "type": "u64"
+          },
+          {
+            "name": "venftEndDay",
+            "type": "u64"
+          },
+          {
+            "name": "perpetualVenftBalance",
+            "type": "u64"
+          },
+          {
+            "name": "numRewardsAccrued",
+            "type": "u64"
+          },
+          {
+            "name": "rewardsSettledDay",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "state",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminId",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAdminId",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardTokenMintId",
+            "type": "publicKey"
+          },
+          {
+            "name": "deployedAt",
+            "type": "i64"
+          },
+          {
+            "name": "lastUpdatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "totalNumLockedNfts",
+            "type": "u64"
+          },
+          {
+            "name": "rewardScheduleId",
+            "type": "publicKey"
+          },
+          {
+            "name": "treasuryBump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "totalRewardsFunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "minLockDurationInDays",
+            "type": "u64"
+          },
+          {
+            "name": "maxLockDurationInDays",
+            "type": "u64"
+          },
+          {
+            "name": "pausedInstructions",
+            "type": "u64"
+          },
+          {
+            "name": "sunsetAt",
+            "type": "i64"
+          },
+          {
+            "name": "adminActionDelayInSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "numQueuedAdminActions",
+            "type": "u64"
+          },
+          {
+            "name": "finalizedAt",
+            "type": "i64"
+          },
+          {
+            "name": "totalRewardsEmitted",
+            "type": "u64"
+          },
+          {
+            "name": "zeroSupplyRewardsPolicy",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsCarriedOver",
+            "type": "u64"
+          },
+          {
+            "name": "rewardsPendingCarryOver",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsReclaimable",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsReclaimed",
+            "type": "u64"
+          },
+          {
+            "name": "earlyUnstakePenaltyBps",
+            "type": "u64"
+          },
+          {
+            "name": "earlyUnstakePenaltyDestination",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsForfeited",
+            "type": "u64"
+          },
+          {
+            "name": "venftSupply",
+            "type": {
+              "array": [
+                "u64",
+                1461
+              ]
+            }
+          },
+          {
+            "name": "rewardsCarriedOver",
+            "type": {
+              "array": [
+                "u64",
+                1461
+              ]
+            }
+          },
+          {
+            "name": "perpetualVenftSupply",
+            "type": "u64"
+          },
+          {
+            "name": "rewardPerVenftIndex",
+            "type": {
+              "array": [
+                "u128",
+                1462
+              ]
+            }
+          },
+          {
+            "name": "dayWeightedRewardPerVenftIndex",
+            "type": {
+              "array": [
+                "u128",
+                1462
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stateId",
+            "type": "publicKey"
+          },
+          {
+            "name": "sealedAt",
+            "type": "i64"
+          },
+          {
+            "name": "numDaysWritten",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardEmission",
+            "type": "u64"
+          },
+          {
+            "name": "emissionCurve",
+            "type": {
+              "defined": "EmissionCurve"
+            }
+          },
+          {
+            "name": "rewardPerDay",
+            "type": {
+              "array": [
+                "u64",
+                1461
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "queuedAdminAction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stateId",
+            "type": "publicKey"
+          },
+          {
+            "name": "actionId",
+            "type": "u64"
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "AdminAction"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "multisig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "signers",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u64"
+          },
+          {
+            "name": "signersSeqno",
+            "type": "u32"
+          },
+          {
+            "name": "signerBump",
+            "type": "u8"
+          },
+          {
+            "name": "numTransactions",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "multisigTransaction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisigId",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionId",
+            "type": "u64"
+          },
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": "MultisigTransactionAccount"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "signersSeqno",
+            "type": "u32"
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "staker",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stakerId",
+            "type": "publicKey"
+          },
+          {
+            "name": "venftBalance",
+            "type": "u64"
+          },
+          {
+            "name": "venftBalanceChange",
+            "type": "i64"
+          },
+          {
+            "name": "venftBalanceChangeDeltas",
+            "type": {
+              "array": [
+                "i64",
+                192
+              ]
+            }
+          },
+          {
+            "name": "venftBreakpoints",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          },
+          {
+            "name": "perpetualVenftBalance",
+            "type": "u64"
+          },
+          {
+            "name": "numLockedNfts",
+            "type": "u64"
+          },
+          {
+            "name": "numRewardsClaimable",
+            "type": "u64"
+          },
+          {
+            "name": "rewardRemainder",
+            "type": "u64"
+          },
+          {
+            "name": "numRewardsForfeitOwed",
+            "type": "u64"
+          },
+          {
+            "name": "lastUpdatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "lastClaimedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "RelockResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "numDaysExtended",
+            "type": "u64"
+          },
+          {
+            "name": "venftAmountMinted",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllowedCreator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "mustBeVerified",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmissionCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "kind",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardEmission",
+            "type": "u64"
+          },
+          {
+            "name": "numDays",
+            "type": "u64"
+          },
+          {
+            "name": "decayRateBps",
+            "type": "u64"
+          },
+          {
+            "name": "halvingIntervalInDays",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MultisigTransactionAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftGatingMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Creator"
+          },
+          {
+            "name": "Collection"
+          },
+          {
+            "name": "CreatorAndCollection"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdminAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SetLockDurationBounds",
+            "fields": [
+              {
+                "name": "min_lock_duration_in_days",
+                "type": "u64"
+              },
+              {
+                "name": "max_lock_duration_in_days",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetPaused",
+            "fields": [
+              {
+                "name": "paused_instructions",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetAdminActionDelay",
+            "fields": [
+              {
+                "name": "admin_action_delay_in_seconds",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetZeroSupplyRewardsPolicy",
+            "fields": [
+              {
+                "name": "zero_supply_rewards_policy",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetEarlyUnstakePenalty",
+            "fields": [
+              {
+                "name": "early_unstake_penalty_bps",
+                "type": "u64"
+              },
+              {
+                "name": "early_unstake_penalty_destination",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "AddAllowedCreator",
+            "fields": [
+              {
+                "name": "address",
+                "type": "publicKey"
+              },
+              {
+                "name": "must_be_verified",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "RemoveAllowedCreator",
+            "fields": [
+              {
+                "name": "address",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "AddAllowedCollection",
+            "fields": [
+              {
+                "name": "collection_mint_id",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveAllowedCollection",
+            "fields": [
+              {
+                "name": "collection_mint_id",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetNftGatingMode",
+            "fields": [
+              {
+                "name": "nft_gating_mode",
+                "type": {
+                  "defined": "NftGatingMode"
+                }
+              }
+            ]
+          },
+          {
+            "name": "WriteRewardSchedule",
+            "fields": [
+              {
+                "name": "day_offset",
+                "type": "u64"
+              },
+              {
+                "name": "reward_per_day",
+                "type": {
+                  "vec": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetEmissionCurve",
+            "fields": [
+              {
+                "name": "emission_curve",
+                "type": {
+                  "defined": "EmissionCurve"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SealRewardSchedule",
+            "fields": [
+              {
+                "name": "total_reward_emission",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Sunset"
+          },
+          {
+            "name": "ReclaimRewards",
+            "fields": [
+              {
+                "name": "destination_id",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SweepTreasury",
+            "fields": [
+              {
+                "name": "destination_id",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "AccountLoaderStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Uninitialized",
+            "fields": [
+              {
+                "defined": "RefMut<'info,T>"
+              }
+            ]
+          },
+          {
+            "name": "Initialized",
+            "fields": [
+              {
+                "defined": "RefMut<'info,T>"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "PoolInitialized",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "adminId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardTokenMintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardScheduleId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "minLockDurationInDays",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxLockDurationInDays",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "deployedAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftStaked",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lockDurationInDays",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "venftAmountMinted",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unlocksAt",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "stakerNumLockedNfts",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalNumLockedNfts",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LockExtended",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "numDaysExtended",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lockDurationInDays",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "venftAmountMinted",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unlocksAt",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "stakerNumLockedNfts",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalNumLockedNfts",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PerpetualLockSet",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "perpetual",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "venftAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unlocksAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftUnstaked",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "numRewardsForfeited",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "stakerNumLockedNfts",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalNumLockedNfts",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftUnstakeSkipped",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "unlocksAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RewardsClaimed",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "numRewardsClaimable",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalRewardsClaimed",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "UnexpectedRewardTokenMintId",
+      "msg": "Unexpected reward token mint ID"
+    },
+    {
+      "code": 6001,
+      "name": "UnexpectedRewardTokenMintDecimals",
+      "msg": "Unexpected reward token mint decimals"
+    },
+    {
+      "code": 6002,
+      "name": "MetadataMintMismatch",
+      "msg": "Unexpected mint ID specified in metadata account"
+    },
+    {
+      "code": 6003,
+      "name": "MetadataHasNoCreators",
+      "msg": "Expected metadata account to specify creators"
+    },
+    {
+      "code": 6004,
+      "name": "MetadataCreatorUnverified",
+      "msg": "Expected creator specified in the metadata account to be verified"
+    },
+    {
+      "code": 6005,
+      "name": "UnexpectedMetadataCreator",
+      "msg": "Unexpected creator specified in metadata account"
+    },
+    {
+      "code": 6006,
+      "name": "StakerIdMismatch",
+      "msg": "Staker ID mismatches user ID"
+    },
+    {
+      "code": 6007,
+      "name": "LockDurationTooSmall",
+      "msg": "Lock duration specified by user is too small"
+    },
+    {
+      "code": 6008,
+      "name": "MaxPossibleLockDurationExceeded",
+      "msg": "Lock duration specified by user exceeds max possible lock duration"
+    },
+    {
+      "code": 6009,
+      "name": "NotYetUnlockable",
+      "msg": "The period which the asset has been specified to be lock for has not yet elapsed"
+    },
+    {
+      "code": 6010,
+      "name": "Unauthorized",
+      "msg": "Signer is not the admin of the pool"
+    },
+    {
+      "code": 6011,
+      "name": "PendingAdminMismatch",
+      "msg": "Signer is not the admin proposed to take over the pool"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidLockDurationBounds",
+      "msg": "Minimum lock duration must be non-zero and may not exceed the maximum lock duration"
+    },
+    {
+      "code": 6013,
+      "name": "MaxLockDurationInUse",
+      "msg": "Maximum lock duration may not be lowered while NFTs are perpetually locked in the pool"
+    },
+    {
+      "code": 6014,
+      "name": "InstructionPaused",
+      "msg": "This instruction has been paused by the admin of the pool"
+    },
+    {
+      "code": 6015,
+      "name": "UnknownPauseFlags",
+      "msg": "Unknown instruction specified to be paused"
+    },
+    {
+      "code": 6016,
+      "name": "AllowedCreatorsFull",
+      "msg": "No more creators may be added to the allowlist of the pool"
+    },
+    {
+      "code": 6017,
+      "name": "AllowedCreatorNotFound",
+      "msg": "Creator is not in the allowlist of the pool"
+    },
+    {
+      "code": 6018,
+      "name": "UnexpectedMetadataCollection",
+      "msg": "Expected metadata account to specify a verified collection allowed by the pool"
+    },
+    {
+      "code": 6019,
+      "name": "AllowedCollectionsFull",
+      "msg": "No more collections may be added to the allowlist of the pool"
+    },
+    {
+      "code": 6020,
+      "name": "AllowedCollectionNotFound",
+      "msg": "Collection is not in the allowlist of the pool"
+    },
+    {
+      "code": 6021,
+      "name": "UnexpectedRewardSchedule",
+      "msg": "Reward schedule account does not belong to the pool"
+    },
+    {
+      "code": 6022,
+      "name": "RewardScheduleSealed",
+      "msg": "Reward schedule has already been sealed"
+    },
+    {
+      "code": 6023,
+      "name": "RewardScheduleNotSealed",
+      "msg": "Reward schedule has yet to be sealed"
+    },
+    {
+      "code": 6024,
+      "name": "RewardScheduleChunkOutOfBounds",
+      "msg": "Reward schedule chunk may not leave gaps nor extend past the end of reward emissions"
+    },
+    {
+      "code": 6025,
+      "name": "RewardScheduleTotalMismatch",
+      "msg": "Total reward emissions in the reward schedule mismatch the intended amount"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidEmissionCurve",
+      "msg": "Emission curve is of an unknown kind or has invalid parameters"
+    },
+    {
+      "code": 6027,
+      "name": "TreasuryInsufficientFunds",
+      "msg": "Treasury does not hold enough reward tokens to pay out the rewards claimed"
+    },
+    {
+      "code": 6028,
+      "name": "PoolSunset",
+      "msg": "The pool has been sunset"
+    },
+    {
+      "code": 6029,
+      "name": "PoolNotSunset",
+      "msg": "The pool has not been sunset"
+    },
+    {
+      "code": 6030,
+      "name": "AdminActionTimelocked",
+      "msg": "Admin actions are timelocked and must be queued through queue_admin_action"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidAdminActionDelay",
+      "msg": "Admin action delay is out of bounds"
+    },
+    {
+      "code": 6032,
+      "name": "UnexpectedAdminActionId",
+      "msg": "Queued admin action ID does not match the number of admin actions queued so far"
+    },
+    {
+      "code": 6033,
+      "name": "AdminActionNotYetExecutable",
+      "msg": "Queued admin action may not be executed yet"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMultisigSigners",
+      "msg": "Multisig signers must be unique, and the threshold must be between one and the number of signers"
+    },
+    {
+      "code": 6035,
+      "name": "NotMultisigSigner",
+      "msg": "Signer is not one of the multisig's signers"
+    },
+    {
+      "code": 6036,
+      "name": "MultisigSignersChanged",
+      "msg": "Multisig signers changed since the transaction was proposed"
+    },
+    {
+      "code": 6037,
+      "name": "MultisigTransactionAlreadyExecuted",
+      "msg": "Multisig transaction has already been executed"
+    },
+    {
+      "code": 6038,
+      "name": "MultisigThresholdNotReached",
+      "msg": "Multisig transaction has not been approved by enough signers"
+    },
+    {
+      "code": 6039,
+      "name": "UnexpectedMultisigTransactionId",
+      "msg": "Multisig transaction ID does not match the number of transactions proposed so far"
+    },
+    {
+      "code": 6040,
+      "name": "NotYetFinalizable",
+      "msg": "Pool may only be finalized once reward emissions and the grace period after them have ended"
+    },
+    {
+      "code": 6041,
+      "name": "AlreadyFinalized",
+      "msg": "Pool has already been finalized"
+    },
+    {
+      "code": 6042,
+      "name": "NotFinalized",
+      "msg": "Pool has not been finalized"
+    },
+    {
+      "code": 6043,
+      "name": "UnknownZeroSupplyRewardsPolicy",
+      "msg": "Unknown zero supply rewards policy"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidEarlyUnstakePenalty",
+      "msg": "Early unstake penalty exceeds 100% or has an unknown destination"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidRemainingAccounts",
+      "msg": "Remaining accounts must be complete groups of the accounts expected for each NFT"
+    },
+    {
+      "code": 6046,
+      "name": "NumLockDurationsMismatch",
+      "msg": "Either a single lock duration or one lock duration per NFT must be provided"
+    },
+    {
+      "code": 6047,
+      "name": "PerpetuallyLocked",
+      "msg": "NFT is perpetually locked"
+    },
+    {
+      "code": 6048,
+      "name": "NotPerpetuallyLocked",
+      "msg": "NFT is not perpetually locked"
+    },
+    {
+      "code": 6049,
+      "name": "UnexpectedDestination",
+      "msg": "Destination token account does not match the one specified by the queued admin action"
+    }
+  ]
+};
+
+export const IDL: SolcietyStakingPool = {
+  "version": "0.1.0",
+  "name": "solciety_staking_pool",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "minLockDurationInDays",
+          "type": "u64"
+        },
+        {
+          "name": "maxLockDurationInDays",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdminId",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdmin",
+      "accounts": [
+        {
+          "name": "newAdmin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setLockDurationBounds",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "minLockDurationInDays",
+          "type": "u64"
+        },
+        {
+          "name": "maxLockDurationInDays",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pausedInstructions",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setAdminActionDelay",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "adminActionDelayInSeconds",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "queueAdminAction",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "queuedAdminAction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "actionId",
+          "type": "u64"
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelAdminAction",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "queuedAdminAction",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeAdminAction",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "queuedAdminAction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createMultisig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "multisigSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "signers",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setMultisigSigners",
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisigSigner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "signers",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "proposeMultisigTransaction",
+      "accounts": [
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTransaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "transactionId",
+          "type": "u64"
+        },
+        {
+          "name": "instructionProgramId",
+          "type": "publicKey"
+        },
+        {
+          "name": "instructionAccounts",
+          "type": {
+            "vec": {
+              "defined": "MultisigTransactionAccount"
+            }
+          }
+        },
+        {
+          "name": "instructionData",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "approveMultisigTransaction",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTransaction",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeMultisigTransaction",
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "multisigTransaction",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sunset",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setZeroSupplyRewardsPolicy",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "zeroSupplyRewardsPolicy",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setEarlyUnstakePenalty",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "earlyUnstakePenaltyBps",
+          "type": "u64"
+        },
+        {
+          "name": "earlyUnstakePenaltyDestination",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "reclaimRewards",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalize",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sweepTreasury",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addAllowedCreator",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        },
+        {
+          "name": "mustBeVerified",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "removeAllowedCreator",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "address",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addAllowedCollection",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collectionMintId",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeAllowedCollection",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collectionMintId",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "writeRewardSchedule",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "dayOffset",
+          "type": "u64"
+        },
+        {
+          "name": "rewardPerDay",
+          "type": {
+            "vec": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setEmissionCurve",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "emissionCurve",
+          "type": {
+            "defined": "EmissionCurve"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sealRewardSchedule",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "totalRewardEmission",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setNftGatingMode",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nftGatingMode",
+          "type": {
+            "defined": "NftGatingMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "stake",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lockDurationInDays",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "stakeFor",
+      "accounts": [
+        {
+          "name": "depositor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "beneficiary",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lockDurationInDays",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "stakeMany",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lockDurationsInDays",
+          "type": {
+            "vec": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimRewardsV2",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimAvailableRewards",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fundTreasury",
+      "accounts": [
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funderRewardToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardTokenTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "extend",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lockDurationInDays",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPerpetualLock",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "perpetual",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "extendAllToMax",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unstakeV2",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unstakeMany",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unstakeEarly",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "returnNft",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "stakerWallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "staker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockedNft",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "authority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stateId",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "poolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stateId",
+            "type": "publicKey"
+          },
+          {
+            "name": "nftGatingMode",
+            "type": {
+              "defined": "NftGatingMode"
+            }
+          },
+          {
+            "name": "allowedCreators",
+            "type": {
+              "vec": {
+                "defined": "AllowedCreator"
+              }
+            }
+          },
+          {
+            "name": "allowedCollections",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "lockedNft",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stakerId",
+            "type": "publicKey"
+          },
+          {
+            "name": "mintId",
+            "type": "publicKey"
+          },
+          {
+            "name": "lockedAt",
+            "type": "i64"
+          },
+          {
+            "name": "lockDurationInDays",
+            "type": "u64"
+          },
+          {
+            "name": "venftStartDay",
+            "type": "u64"
+          },
+          {
+            "name": "venftStartBalance",
+            "type": "u64"
+          },
+          {
+            "name": "venftDecayingBalance",
+            "type": "u64"
+          },
+          {
+            "name": "venftEndDay",
+            "type": "u64"
+          },
+          {
+            "name": "perpetualVenftBalance",
+            "type": "u64"
+          },
+          {
+            "name": "numRewardsAccrued",
+            "type": "u64"
+          },
+          {
+            "name": "rewardsSettledDay",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "state",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminId",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAdminId",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardTokenMintId",
+            "type": "publicKey"
+          },
+          {
+            "name": "deployedAt",
+            "type": "i64"
+          },
+          {
+            "name": "lastUpdatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "totalNumLockedNfts",
+            "type": "u64"
+          },
+          {
+            "name": "rewardScheduleId",
+            "type": "publicKey"
+          },
+          {
+            "name": "treasuryBump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "totalRewardsFunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "minLockDurationInDays",
+            "type": "u64"
+          },
+          {
+            "name": "maxLockDurationInDays",
+            "type": "u64"
+          },
+          {
+            "name": "pausedInstructions",
+            "type": "u64"
+          },
+          {
+            "name": "sunsetAt",
+            "type": "i64"
+          },
+          {
+            "name": "adminActionDelayInSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "numQueuedAdminActions",
+            "type": "u64"
+          },
+          {
+            "name": "finalizedAt",
+            "type": "i64"
+          },
+          {
+            "name": "totalRewardsEmitted",
+            "type": "u64"
+          },
+          {
+            "name": "zeroSupplyRewardsPolicy",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsCarriedOver",
+            "type": "u64"
+          },
+          {
+            "name": "rewardsPendingCarryOver",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsReclaimable",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsReclaimed",
+            "type": "u64"
+          },
+          {
+            "name": "earlyUnstakePenaltyBps",
+            "type": "u64"
+          },
+          {
+            "name": "earlyUnstakePenaltyDestination",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardsForfeited",
+            "type": "u64"
+          },
+          {
+            "name": "venftSupply",
+            "type": {
+              "array": [
+                "u64",
+                1461
+              ]
+            }
+          },
           {
-            "name": "lockDurationInDays",
+            "name": "rewardsCarriedOver",
+            "type": {
+              "array": [
+                "u64",
+                1461
+              ]
+            }
+          },
+          {
+            "name": "perpetualVenftSupply",
             "type": "u64"
+          },
+          {
+            "name": "rewardPerVenftIndex",
+            "type": {
+              "array": [
+                "u128",
+                1462
+              ]
+            }
+          },
+          {
+            "name": "dayWeightedRewardPerVenftIndex",
+            "type": {
+              "array": [
+                "u128",
+                1462
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "state",
+      "name": "rewardSchedule",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "adminId",
-            "type": "publicKey"
-          },
-          {
-            "name": "rewardTokenMintId",
+            "name": "stateId",
             "type": "publicKey"
           },
           {
-            "name": "deployedAt",
+            "name": "sealedAt",
             "type": "i64"
           },
           {
-            "name": "lastUpdatedAt",
-            "type": "i64"
+            "name": "numDaysWritten",
+            "type": "u64"
           },
           {
-            "name": "totalNumLockedNfts",
+            "name": "totalRewardEmission",
             "type": "u64"
           },
           {
-            "name": "venftSupply",
+            "name": "emissionCurve",
+            "type": {
+              "defined": "EmissionCurve"
+            }
+          },
+          {
+            "name": "rewardPerDay",
             "type": {
               "array": [
                 "u64",
@@ -860,6 +4813,112 @@ export const IDL: SolcietyStakingPool = {
         ]
       }
     },
+    {
+      "name": "queuedAdminAction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stateId",
+            "type": "publicKey"
+          },
+          {
+            "name": "actionId",
+            "type": "u64"
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "AdminAction"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "multisig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "signers",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u64"
+          },
+          {
+            "name": "signersSeqno",
+            "type": "u32"
+          },
+          {
+            "name": "signerBump",
+            "type": "u8"
+          },
+          {
+            "name": "numTransactions",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "multisigTransaction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "multisigId",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionId",
+            "type": "u64"
+          },
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": "MultisigTransactionAccount"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "bool"
+            }
+          },
+          {
+            "name": "signersSeqno",
+            "type": "u32"
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
     {
       "name": "staker",
       "type": {
@@ -871,13 +4930,34 @@ export const IDL: SolcietyStakingPool = {
           },
           {
             "name": "venftBalance",
+            "type": "u64"
+          },
+          {
+            "name": "venftBalanceChange",
+            "type": "i64"
+          },
+          {
+            "name": "venftBalanceChangeDeltas",
+            "type": {
+              "array": [
+                "i64",
+                192
+              ]
+            }
+          },
+          {
+            "name": "venftBreakpoints",
             "type": {
               "array": [
                 "u64",
-                183
+                3
               ]
             }
           },
+          {
+            "name": "perpetualVenftBalance",
+            "type": "u64"
+          },
           {
             "name": "numLockedNfts",
             "type": "u64"
@@ -886,6 +4966,14 @@ export const IDL: SolcietyStakingPool = {
             "name": "numRewardsClaimable",
             "type": "u64"
           },
+          {
+            "name": "rewardRemainder",
+            "type": "u64"
+          },
+          {
+            "name": "numRewardsForfeitOwed",
+            "type": "u64"
+          },
           {
             "name": "lastUpdatedAt",
             "type": "i64"
@@ -893,6 +4981,19 @@ export const IDL: SolcietyStakingPool = {
           {
             "name": "lastClaimedAt",
             "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
           }
         ]
       }
@@ -900,39 +5001,266 @@ export const IDL: SolcietyStakingPool = {
   ],
   "types": [
     {
-      "name": "ErrorCode",
+      "name": "RelockResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "numDaysExtended",
+            "type": "u64"
+          },
+          {
+            "name": "venftAmountMinted",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllowedCreator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "mustBeVerified",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmissionCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "kind",
+            "type": "u64"
+          },
+          {
+            "name": "totalRewardEmission",
+            "type": "u64"
+          },
+          {
+            "name": "numDays",
+            "type": "u64"
+          },
+          {
+            "name": "decayRateBps",
+            "type": "u64"
+          },
+          {
+            "name": "halvingIntervalInDays",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MultisigTransactionAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftGatingMode",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "UnexpectedRewardTokenMintId"
+            "name": "Creator"
+          },
+          {
+            "name": "Collection"
+          },
+          {
+            "name": "CreatorAndCollection"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdminAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SetLockDurationBounds",
+            "fields": [
+              {
+                "name": "min_lock_duration_in_days",
+                "type": "u64"
+              },
+              {
+                "name": "max_lock_duration_in_days",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetPaused",
+            "fields": [
+              {
+                "name": "paused_instructions",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetAdminActionDelay",
+            "fields": [
+              {
+                "name": "admin_action_delay_in_seconds",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetZeroSupplyRewardsPolicy",
+            "fields": [
+              {
+                "name": "zero_supply_rewards_policy",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetEarlyUnstakePenalty",
+            "fields": [
+              {
+                "name": "early_unstake_penalty_bps",
+                "type": "u64"
+              },
+              {
+                "name": "early_unstake_penalty_destination",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "AddAllowedCreator",
+            "fields": [
+              {
+                "name": "address",
+                "type": "publicKey"
+              },
+              {
+                "name": "must_be_verified",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "RemoveAllowedCreator",
+            "fields": [
+              {
+                "name": "address",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "UnexpectedRewardTokenMintDecimals"
+            "name": "AddAllowedCollection",
+            "fields": [
+              {
+                "name": "collection_mint_id",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "MetadataMintMismatch"
+            "name": "RemoveAllowedCollection",
+            "fields": [
+              {
+                "name": "collection_mint_id",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "MetadataHasNoCreators"
+            "name": "SetNftGatingMode",
+            "fields": [
+              {
+                "name": "nft_gating_mode",
+                "type": {
+                  "defined": "NftGatingMode"
+                }
+              }
+            ]
           },
           {
-            "name": "MetadataCreatorUnverified"
+            "name": "WriteRewardSchedule",
+            "fields": [
+              {
+                "name": "day_offset",
+                "type": "u64"
+              },
+              {
+                "name": "reward_per_day",
+                "type": {
+                  "vec": "u64"
+                }
+              }
+            ]
           },
           {
-            "name": "UnexpectedMetadataCreator"
+            "name": "SetEmissionCurve",
+            "fields": [
+              {
+                "name": "emission_curve",
+                "type": {
+                  "defined": "EmissionCurve"
+                }
+              }
+            ]
           },
           {
-            "name": "StakerIdMismatch"
+            "name": "SealRewardSchedule",
+            "fields": [
+              {
+                "name": "total_reward_emission",
+                "type": "u64"
+              }
+            ]
           },
           {
-            "name": "LockDurationTooSmall"
+            "name": "Sunset"
           },
           {
-            "name": "MaxPossibleLockDurationExceeded"
+            "name": "ReclaimRewards",
+            "fields": [
+              {
+                "name": "destination_id",
+                "type": "publicKey"
+              }
+            ]
           },
           {
-            "name": "NotYetUnlockable"
+            "name": "SweepTreasury",
+            "fields": [
+              {
+                "name": "destination_id",
+                "type": "publicKey"
+              }
+            ]
           }
         ]
       }
@@ -961,5 +5289,519 @@ export const IDL: SolcietyStakingPool = {
         ]
       }
     }
+  ],
+  "events": [
+    {
+      "name": "PoolInitialized",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "adminId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardTokenMintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardScheduleId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "minLockDurationInDays",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxLockDurationInDays",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "deployedAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftStaked",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lockDurationInDays",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "venftAmountMinted",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unlocksAt",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "stakerNumLockedNfts",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalNumLockedNfts",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LockExtended",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "numDaysExtended",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lockDurationInDays",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "venftAmountMinted",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unlocksAt",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "stakerNumLockedNfts",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalNumLockedNfts",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PerpetualLockSet",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "perpetual",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "venftAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unlocksAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftUnstaked",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "numRewardsForfeited",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "stakerNumLockedNfts",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalNumLockedNfts",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NftUnstakeSkipped",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "unlocksAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RewardsClaimed",
+      "fields": [
+        {
+          "name": "stateId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakerId",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "numRewardsClaimable",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalRewardsClaimed",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "UnexpectedRewardTokenMintId",
+      "msg": "Unexpected reward token mint ID"
+    },
+    {
+      "code": 6001,
+      "name": "UnexpectedRewardTokenMintDecimals",
+      "msg": "Unexpected reward token mint decimals"
+    },
+    {
+      "code": 6002,
+      "name": "MetadataMintMismatch",
+      "msg": "Unexpected mint ID specified in metadata account"
+    },
+    {
+      "code": 6003,
+      "name": "MetadataHasNoCreators",
+      "msg": "Expected metadata account to specify creators"
+    },
+    {
+      "code": 6004,
+      "name": "MetadataCreatorUnverified",
+      "msg": "Expected creator specified in the metadata account to be verified"
+    },
+    {
+      "code": 6005,
+      "name": "UnexpectedMetadataCreator",
+      "msg": "Unexpected creator specified in metadata account"
+    },
+    {
+      "code": 6006,
+      "name": "StakerIdMismatch",
+      "msg": "Staker ID mismatches user ID"
+    },
+    {
+      "code": 6007,
+      "name": "LockDurationTooSmall",
+      "msg": "Lock duration specified by user is too small"
+    },
+    {
+      "code": 6008,
+      "name": "MaxPossibleLockDurationExceeded",
+      "msg": "Lock duration specified by user exceeds max possible lock duration"
+    },
+    {
+      "code": 6009,
+      "name": "NotYetUnlockable",
+      "msg": "The period which the asset has been specified to be lock for has not yet elapsed"
+    },
+    {
+      "code": 6010,
+      "name": "Unauthorized",
+      "msg": "Signer is not the admin of the pool"
+    },
+    {
+      "code": 6011,
+      "name": "PendingAdminMismatch",
+      "msg": "Signer is not the admin proposed to take over the pool"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidLockDurationBounds",
+      "msg": "Minimum lock duration must be non-zero and may not exceed the maximum lock duration"
+    },
+    {
+      "code": 6013,
+      "name": "MaxLockDurationInUse",
+      "msg": "Maximum lock duration may not be lowered while NFTs are perpetually locked in the pool"
+    },
+    {
+      "code": 6014,
+      "name": "InstructionPaused",
+      "msg": "This instruction has been paused by the admin of the pool"
+    },
+    {
+      "code": 6015,
+      "name": "UnknownPauseFlags",
+      "msg": "Unknown instruction specified to be paused"
+    },
+    {
+      "code": 6016,
+      "name": "AllowedCreatorsFull",
+      "msg": "No more creators may be added to the allowlist of the pool"
+    },
+    {
+      "code": 6017,
+      "name": "AllowedCreatorNotFound",
+      "msg": "Creator is not in the allowlist of the pool"
+    },
+    {
+      "code": 6018,
+      "name": "UnexpectedMetadataCollection",
+      "msg": "Expected metadata account to specify a verified collection allowed by the pool"
+    },
+    {
+      "code": 6019,
+      "name": "AllowedCollectionsFull",
+      "msg": "No more collections may be added to the allowlist of the pool"
+    },
+    {
+      "code": 6020,
+      "name": "AllowedCollectionNotFound",
+      "msg": "Collection is not in the allowlist of the pool"
+    },
+    {
+      "code": 6021,
+      "name": "UnexpectedRewardSchedule",
+      "msg": "Reward schedule account does not belong to the pool"
+    },
+    {
+      "code": 6022,
+      "name": "RewardScheduleSealed",
+      "msg": "Reward schedule has already been sealed"
+    },
+    {
+      "code": 6023,
+      "name": "RewardScheduleNotSealed",
+      "msg": "Reward schedule has yet to be sealed"
+    },
+    {
+      "code": 6024,
+      "name": "RewardScheduleChunkOutOfBounds",
+      "msg": "Reward schedule chunk may not leave gaps nor extend past the end of reward emissions"
+    },
+    {
+      "code": 6025,
+      "name": "RewardScheduleTotalMismatch",
+      "msg": "Total reward emissions in the reward schedule mismatch the intended amount"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidEmissionCurve",
+      "msg": "Emission curve is of an unknown kind or has invalid parameters"
+    },
+    {
+      "code": 6027,
+      "name": "TreasuryInsufficientFunds",
+      "msg": "Treasury does not hold enough reward tokens to pay out the rewards claimed"
+    },
+    {
+      "code": 6028,
+      "name": "PoolSunset",
+      "msg": "The pool has been sunset"
+    },
+    {
+      "code": 6029,
+      "name": "PoolNotSunset",
+      "msg": "The pool has not been sunset"
+    },
+    {
+      "code": 6030,
+      "name": "AdminActionTimelocked",
+      "msg": "Admin actions are timelocked and must be queued through queue_admin_action"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidAdminActionDelay",
+      "msg": "Admin action delay is out of bounds"
+    },
+    {
+      "code": 6032,
+      "name": "UnexpectedAdminActionId",
+      "msg": "Queued admin action ID does not match the number of admin actions queued so far"
+    },
+    {
+      "code": 6033,
+      "name": "AdminActionNotYetExecutable",
+      "msg": "Queued admin action may not be executed yet"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMultisigSigners",
+      "msg": "Multisig signers must be unique, and the threshold must be between one and the number of signers"
+    },
+    {
+      "code": 6035,
+      "name": "NotMultisigSigner",
+      "msg": "Signer is not one of the multisig's signers"
+    },
+    {
+      "code": 6036,
+      "name": "MultisigSignersChanged",
+      "msg": "Multisig signers changed since the transaction was proposed"
+    },
+    {
+      "code": 6037,
+      "name": "MultisigTransactionAlreadyExecuted",
+      "msg": "Multisig transaction has already been executed"
+    },
+    {
+      "code": 6038,
+      "name": "MultisigThresholdNotReached",
+      "msg": "Multisig transaction has not been approved by enough signers"
+    },
+    {
+      "code": 6039,
+      "name": "UnexpectedMultisigTransactionId",
+      "msg": "Multisig transaction ID does not match the number of transactions proposed so far"
+    },
+    {
+      "code": 6040,
+      "name": "NotYetFinalizable",
+      "msg": "Pool may only be finalized once reward emissions and the grace period after them have ended"
+    },
+    {
+      "code": 6041,
+      "name": "AlreadyFinalized",
+      "msg": "Pool has already been finalized"
+    },
+    {
+      "code": 6042,
+      "name": "NotFinalized",
+      "msg": "Pool has not been finalized"
+    },
+    {
+      "code": 6043,
+      "name": "UnknownZeroSupplyRewardsPolicy",
+      "msg": "Unknown zero supply rewards policy"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidEarlyUnstakePenalty",
+      "msg": "Early unstake penalty exceeds 100% or has an unknown destination"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidRemainingAccounts",
+      "msg": "Remaining accounts must be complete groups of the accounts expected for each NFT"
+    },
+    {
+      "code": 6046,
+      "name": "NumLockDurationsMismatch",
+      "msg": "Either a single lock duration or one lock duration per NFT must be provided"
+    },
+    {
+      "code": 6047,
+      "name": "PerpetuallyLocked",
+      "msg": "NFT is perpetually locked"
+    },
+    {
+      "code": 6048,
+      "name": "NotPerpetuallyLocked",
+      "msg": "NFT is not perpetually locked"
+    },
+    {
+      "code": 6049,
+      "name": "UnexpectedDestination",
+      "msg": "Destination token account does not match the one specified by the queued admin action"
+    }
   ]
 };
@@ -3,7 +3,7 @@ use std::convert::TryInto;
 use std::ops::DerefMut;
 
 use anchor_lang::__private::bytemuck;
-use anchor_lang::{prelude::*, ZeroCopy};
+use anchor_lang::{prelude::*, AccountsClose, ZeroCopy};
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
@@ -14,14 +14,83 @@ use quickcheck_macros::quickcheck;
 
 declare_id!("FqNuLBJt753qBon7cFWxknyGwKYFY8WZ8xoYN5ynXCBx");
 
+/// Bounds on the minimum and maximum lock durations which a pool may be configured with.
 pub const MIN_LOCK_DURATION_DAYS: u64 = 1; // 1 day
 pub const MAX_LOCK_DURATION_DAYS: u64 = 365 / 2; // 6 months
 
 pub const MAX_DURATION_TO_EMIT_REWARDS_DAYS: u64 = 4 * 365 + 1; // 4 years
 
+/// Number of days after reward emissions end before the pool may be finalized.
+pub const FINALIZATION_GRACE_PERIOD_DAYS: u64 = 30; // 30 days
+
+/// veNFT's minted per day a NFT is locked for, which is also the amount by which they decay
+/// every day.
+pub const VENFTS_PER_DAY: u64 = 1_000_000_000;
+
+/// Number of days ahead of the day they were last updated over which stakers track changes in
+/// their veNFT balance.
+///
+/// A locked NFT's veNFT's change slope at most `MAX_LOCK_DURATION_DAYS + 2` days after they are
+/// minted, so the ledger is sized to the largest lock any pool may be configured with, rounded up
+/// to a multiple of 64 days. Every staker account pays rent for the full ledger of ~1.5KiB no
+/// matter the maximum lock duration of its pool, which keeps the account layout the same across
+/// pools.
+pub const STAKER_LEDGER_LEN: u64 = (MAX_LOCK_DURATION_DAYS + 2 + 63) / 64 * 64;
+
 pub const REWARD_TOKEN_DECIMALS: u8 = 9;
 pub const SUBUNITS_PER_REWARD_TOKEN: u64 = 1_000_000_000;
 
+/// Fixed-point precision of `State::reward_per_venft_index`, and of the fraction of a reward token
+/// subunit stakers carry between updates.
+pub const REWARD_INDEX_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// Flags which may be set in `State::paused_instructions` to pause individual instructions.
+/// Unstaking NFTs whose locks have elapsed may never be paused.
+pub const PAUSE_STAKE: u64 = 1 << 0;
+pub const PAUSE_EXTEND: u64 = 1 << 1;
+pub const PAUSE_CLAIM_REWARDS: u64 = 1 << 2;
+pub const PAUSE_ALL: u64 = PAUSE_STAKE | PAUSE_EXTEND | PAUSE_CLAIM_REWARDS;
+
+/// Policies for what happens to rewards emitted on days on which no veNFT's are in circulation.
+/// Such rewards are either set aside to be reclaimed by the admin, which is the default, or
+/// carried forward to the next day on which veNFT's are in circulation.
+pub const ZERO_SUPPLY_REWARDS_RECLAIMABLE: u64 = 0;
+pub const ZERO_SUPPLY_REWARDS_CARRY_FORWARD: u64 = 1;
+
+/// Where rewards forfeited by stakers unstaking their NFTs early go. Forfeited rewards are either
+/// left in the treasury to be reclaimed by the admin, or carried forward to the other stakers.
+pub const EARLY_UNSTAKE_PENALTY_TO_TREASURY: u64 = 0;
+pub const EARLY_UNSTAKE_PENALTY_TO_STAKERS: u64 = 1;
+
+/// Number of remaining accounts supplied per NFT to `stake_many`: the NFT's mint, its metadata,
+/// the staker's associated token account holding it, and the escrow and locked NFT accounts to be
+/// created for it.
+pub const STAKE_MANY_ACCOUNTS_PER_NFT: usize = 5;
+
+/// Number of remaining accounts supplied per NFT to `unstake_many`: the NFT's mint, the staker's
+/// associated token account to return it to, and its escrow and locked NFT accounts.
+pub const UNSTAKE_MANY_ACCOUNTS_PER_NFT: usize = 4;
+
+/// Upper bound on how long admin actions may be timelocked for once queued.
+pub const MAX_ADMIN_ACTION_DELAY_SECONDS: i64 = 30 * 24 * 60 * 60; // 30 days
+
+/// Maximum number of days of the reward schedule which may be written by a single queued admin
+/// action.
+pub const MAX_REWARD_SCHEDULE_CHUNK_LEN: usize = 128;
+
+pub const MAX_NUM_MULTISIG_SIGNERS: usize = 10;
+
+/// Kinds of emission curves a reward schedule may follow. Emissions of every curve other than
+/// `EMISSION_CURVE_TABLE` are computed from `EmissionCurve`'s parameters rather than uploaded.
+pub const EMISSION_CURVE_TABLE: u64 = 0;
+pub const EMISSION_CURVE_CONSTANT: u64 = 1;
+pub const EMISSION_CURVE_LINEAR_DECAY: u64 = 2;
+pub const EMISSION_CURVE_EXPONENTIAL_DECAY: u64 = 3;
+pub const EMISSION_CURVE_STEPPED_HALVING: u64 = 4;
+
+pub const MAX_NUM_ALLOWED_CREATORS: usize = 16;
+pub const MAX_NUM_ALLOWED_COLLECTIONS: usize = 8;
+
 #[error_code]
 pub enum ErrorCode {
     #[msg("Unexpected reward token mint ID")]
@@ -44,13 +113,99 @@ pub enum ErrorCode {
     MaxPossibleLockDurationExceeded,
     #[msg("The period which the asset has been specified to be lock for has not yet elapsed")]
     NotYetUnlockable,
+    #[msg("Signer is not the admin of the pool")]
+    Unauthorized,
+    #[msg("Signer is not the admin proposed to take over the pool")]
+    PendingAdminMismatch,
+    #[msg("Minimum lock duration must be non-zero and may not exceed the maximum lock duration")]
+    InvalidLockDurationBounds,
+    #[msg(
+        "Maximum lock duration may not be lowered while NFTs are perpetually locked in the pool"
+    )]
+    MaxLockDurationInUse,
+    #[msg("This instruction has been paused by the admin of the pool")]
+    InstructionPaused,
+    #[msg("Unknown instruction specified to be paused")]
+    UnknownPauseFlags,
+    #[msg("No more creators may be added to the allowlist of the pool")]
+    AllowedCreatorsFull,
+    #[msg("Creator is not in the allowlist of the pool")]
+    AllowedCreatorNotFound,
+    #[msg("Expected metadata account to specify a verified collection allowed by the pool")]
+    UnexpectedMetadataCollection,
+    #[msg("No more collections may be added to the allowlist of the pool")]
+    AllowedCollectionsFull,
+    #[msg("Collection is not in the allowlist of the pool")]
+    AllowedCollectionNotFound,
+    #[msg("Reward schedule account does not belong to the pool")]
+    UnexpectedRewardSchedule,
+    #[msg("Reward schedule has already been sealed")]
+    RewardScheduleSealed,
+    #[msg("Reward schedule has yet to be sealed")]
+    RewardScheduleNotSealed,
+    #[msg("Reward schedule chunk may not leave gaps nor extend past the end of reward emissions")]
+    RewardScheduleChunkOutOfBounds,
+    #[msg("Total reward emissions in the reward schedule mismatch the intended amount")]
+    RewardScheduleTotalMismatch,
+    #[msg("Emission curve is of an unknown kind or has invalid parameters")]
+    InvalidEmissionCurve,
+    #[msg("Treasury does not hold enough reward tokens to pay out the rewards claimed")]
+    TreasuryInsufficientFunds,
+    #[msg("The pool has been sunset")]
+    PoolSunset,
+    #[msg("The pool has not been sunset")]
+    PoolNotSunset,
+    #[msg("Admin actions are timelocked and must be queued through queue_admin_action")]
+    AdminActionTimelocked,
+    #[msg("Admin action delay is out of bounds")]
+    InvalidAdminActionDelay,
+    #[msg("Queued admin action ID does not match the number of admin actions queued so far")]
+    UnexpectedAdminActionId,
+    #[msg("Queued admin action may not be executed yet")]
+    AdminActionNotYetExecutable,
+    #[msg("Multisig signers must be unique, and the threshold must be between one and the number of signers")]
+    InvalidMultisigSigners,
+    #[msg("Signer is not one of the multisig's signers")]
+    NotMultisigSigner,
+    #[msg("Multisig signers changed since the transaction was proposed")]
+    MultisigSignersChanged,
+    #[msg("Multisig transaction has already been executed")]
+    MultisigTransactionAlreadyExecuted,
+    #[msg("Multisig transaction has not been approved by enough signers")]
+    MultisigThresholdNotReached,
+    #[msg("Multisig transaction ID does not match the number of transactions proposed so far")]
+    UnexpectedMultisigTransactionId,
+    #[msg("Pool may only be finalized once reward emissions and the grace period after them have ended")]
+    NotYetFinalizable,
+    #[msg("Pool has already been finalized")]
+    AlreadyFinalized,
+    #[msg("Pool has not been finalized")]
+    NotFinalized,
+    #[msg("Unknown zero supply rewards policy")]
+    UnknownZeroSupplyRewardsPolicy,
+    #[msg("Early unstake penalty exceeds 100% or has an unknown destination")]
+    InvalidEarlyUnstakePenalty,
+    #[msg("Remaining accounts must be complete groups of the accounts expected for each NFT")]
+    InvalidRemainingAccounts,
+    #[msg("Either a single lock duration or one lock duration per NFT must be provided")]
+    NumLockDurationsMismatch,
+    #[msg("NFT is perpetually locked")]
+    PerpetuallyLocked,
+    #[msg("NFT is not perpetually locked")]
+    NotPerpetuallyLocked,
+    #[msg("Destination token account does not match the one specified by the queued admin action")]
+    UnexpectedDestination,
 }
 
 #[program]
 pub mod solciety_staking_pool {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        min_lock_duration_in_days: u64,
+        max_lock_duration_in_days: u64,
+    ) -> Result<()> {
         require!(
             ctx.accounts.reward_token_mint.decimals == REWARD_TOKEN_DECIMALS,
             UnexpectedRewardTokenMintDecimals
@@ -64,749 +219,5785 @@ pub mod solciety_staking_pool {
         state.deployed_at = clock.unix_timestamp;
         state.last_updated_at = clock.unix_timestamp;
         state.total_num_locked_nfts = 0;
+        state.set_lock_duration_bounds(min_lock_duration_in_days, max_lock_duration_in_days)?;
+        state.reward_schedule_id = ctx.accounts.reward_schedule.key();
+        state.treasury_bump = *ctx.bumps.get("reward_token_treasury").unwrap();
         state.venft_supply = [0u64; MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize];
 
-        (*ctx.accounts.authority).state_id = ctx.accounts.state.key();
+        let reward_schedule = &mut ctx.accounts.reward_schedule.load_init()?;
+        reward_schedule.state_id = ctx.accounts.state.key();
+
+        ctx.accounts.authority.state_id = ctx.accounts.state.key();
+        ctx.accounts.authority.bump = *ctx.bumps.get("authority").unwrap();
+        ctx.accounts.pool_config.state_id = ctx.accounts.state.key();
+
+        emit!(PoolInitialized {
+            state_id: ctx.accounts.state.key(),
+            admin_id: state.admin_id,
+            reward_token_mint_id: state.reward_token_mint_id,
+            reward_schedule_id: state.reward_schedule_id,
+            min_lock_duration_in_days: state.min_lock_duration_in_days,
+            max_lock_duration_in_days: state.max_lock_duration_in_days,
+            deployed_at: state.deployed_at,
+        });
 
         Ok(())
     }
 
-    pub fn stake(ctx: Context<Stake>, lock_duration_in_days: u64) -> Result<()> {
-        let clock = Clock::get()?;
+    /// Propose a new admin for the pool. The proposed admin only takes over once they call
+    /// `accept_admin`. Proposing the default public key cancels any pending proposal.
+    pub fn propose_admin(ctx: Context<AdminOnly>, new_admin_id: Pubkey) -> Result<()> {
+        let state = &mut ctx.accounts.state.load_mut()?;
+        state.propose_admin(new_admin_id);
 
-        assert_lockable_nft(ctx.accounts.nft_mint.key(), &ctx.accounts.nft_metadata)?;
+        Ok(())
+    }
 
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
         let state = &mut ctx.accounts.state.load_mut()?;
-        let staker = &mut match load_maybe_init_mut(&ctx.accounts.staker)? {
-            AccountLoaderStatus::Initialized(staker) => {
-                require!(
-                    ctx.accounts.user.key() == staker.staker_id,
-                    StakerIdMismatch,
-                );
-                staker
-            }
-            AccountLoaderStatus::Uninitialized(mut staker) => {
-                staker.staker_id = ctx.accounts.user.key();
-                staker.venft_balance = [0u64; (MAX_LOCK_DURATION_DAYS + 1) as usize];
-                staker.num_locked_nfts = 0;
-                staker.num_rewards_claimable = 0;
-                staker.last_updated_at = clock.unix_timestamp;
-                staker.last_claimed_at = 0;
-                staker
-            }
-        };
+        state.accept_admin(ctx.accounts.new_admin.key())?;
 
-        let locked_nft = &mut ctx.accounts.locked_nft.load_init()?;
-        locked_nft.staker_id = ctx.accounts.user.key();
-        locked_nft.mint_id = ctx.accounts.nft_mint.key();
-        locked_nft.locked_at = clock.unix_timestamp;
-        locked_nft.lock_duration_in_days = lock_duration_in_days;
+        Ok(())
+    }
 
-        state.update(clock.unix_timestamp);
-        staker.update(&state, clock.unix_timestamp);
+    pub fn set_lock_duration_bounds(
+        ctx: Context<AdminOnly>,
+        min_lock_duration_in_days: u64,
+        max_lock_duration_in_days: u64,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state.load_mut()?;
+        state.assert_not_timelocked()?;
+        state.set_lock_duration_bounds(min_lock_duration_in_days, max_lock_duration_in_days)?;
 
-        staker.stake_nft(state);
-        staker.mint_venfts(state, clock.unix_timestamp, lock_duration_in_days)?;
+        Ok(())
+    }
 
-        drop(state);
-        drop(staker);
+    /// Replace the set of paused instructions with `paused_instructions`, a combination of the
+    /// `PAUSE_*` flags. Passing zero unpauses the pool. Instructions may always be paused
+    /// immediately, while unpausing them is timelocked.
+    pub fn set_paused(ctx: Context<AdminOnly>, paused_instructions: u64) -> Result<()> {
+        let state = &mut ctx.accounts.state.load_mut()?;
+        state.assert_may_set_paused(paused_instructions)?;
+        state.set_paused(paused_instructions)?;
 
-        anchor_spl::token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token::Transfer {
-                    from: ctx.accounts.nft.to_account_info(),
-                    to: ctx.accounts.nft_escrow.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
-            1,
-        )?;
+        Ok(())
+    }
+
+    /// Set how long admin actions queued through `queue_admin_action` are timelocked for. Once
+    /// set, pool parameters, allowlists and the reward schedule may only be changed, instructions
+    /// may only be unpaused, the pool may only be sunset, and rewards may only be reclaimed or
+    /// swept through queued admin actions. The delay may only be lowered through a queued admin
+    /// action as well.
+    pub fn set_admin_action_delay(
+        ctx: Context<AdminOnly>,
+        admin_action_delay_in_seconds: i64,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state.load_mut()?;
+        if admin_action_delay_in_seconds < state.admin_action_delay_in_seconds {
+            state.assert_not_timelocked()?;
+        }
+        state.set_admin_action_delay(admin_action_delay_in_seconds)?;
 
         Ok(())
     }
 
-    pub fn claim_rewards(ctx: Context<ClaimRewards>, authority_bump: u8) -> Result<()> {
+    /// Queue an admin action which may be executed through `execute_admin_action` once the pool's
+    /// admin action delay has passed. `action_id` must be the number of admin actions queued so
+    /// far.
+    pub fn queue_admin_action(
+        ctx: Context<QueueAdminAction>,
+        action_id: u64,
+        action: AdminAction,
+    ) -> Result<()> {
         let clock = Clock::get()?;
 
         let state = &mut ctx.accounts.state.load_mut()?;
         require!(
-            ctx.accounts.reward_token_mint.key() == state.reward_token_mint_id,
-            UnexpectedRewardTokenMintId
+            action_id == state.num_queued_admin_actions,
+            UnexpectedAdminActionId
         );
-        let staker = &mut ctx.accounts.staker.load_mut()?;
-        require!(
-            ctx.accounts.user.key() == staker.staker_id,
-            StakerIdMismatch
+        action.validate()?;
+
+        state.num_queued_admin_actions += 1;
+
+        let queued_admin_action = &mut ctx.accounts.queued_admin_action;
+        queued_admin_action.state_id = ctx.accounts.state.key();
+        queued_admin_action.action_id = action_id;
+        queued_admin_action.queued_at = clock.unix_timestamp;
+        queued_admin_action.executable_at = clock
+            .unix_timestamp
+            .saturating_add(state.admin_action_delay_in_seconds);
+        queued_admin_action.action = action;
+
+        Ok(())
+    }
+
+    pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+        msg!(
+            "Cancelled admin action {}",
+            ctx.accounts.queued_admin_action.action_id
         );
 
-        state.update(clock.unix_timestamp);
-        staker.update(state, clock.unix_timestamp);
+        Ok(())
+    }
+
+    /// Apply a queued admin action once its delay has passed. Reward tokens reclaimed or swept by
+    /// the action are transferred from the treasury to `destination`, which must be the token
+    /// account specified by the action. Any token account may be passed as `destination`
+    /// otherwise.
+    pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        let queued_admin_action = &ctx.accounts.queued_admin_action;
+        queued_admin_action.assert_executable(clock.unix_timestamp)?;
+
+        if let Some(destination_id) = queued_admin_action.action.destination_id() {
+            require!(
+                ctx.accounts.destination.key() == destination_id,
+                UnexpectedDestination
+            );
+        }
+
+        let mut state = ctx.accounts.state.load_mut()?;
+        let mut reward_schedule = ctx.accounts.reward_schedule.load_mut()?;
 
-        let num_tokens_rewarded = staker.claim_rewards(clock.unix_timestamp);
+        let amount_transferred = queued_admin_action.action.apply(
+            &mut state,
+            &mut ctx.accounts.pool_config,
+            &mut reward_schedule,
+            ctx.accounts.reward_token_treasury.amount,
+            clock.unix_timestamp,
+        )?;
 
         drop(state);
-        drop(staker);
+        drop(reward_schedule);
+
+        if amount_transferred == 0 {
+            return Ok(());
+        }
+
+        let state_id = ctx.accounts.state.key();
+        let authority_bump = ctx.accounts.authority.bump;
 
         anchor_spl::token::transfer(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 anchor_spl::token::Transfer {
                     from: ctx.accounts.reward_token_treasury.to_account_info(),
-                    to: ctx.accounts.reward_token.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
                     authority: ctx.accounts.authority.to_account_info(),
                 },
-                &[&[b"authority", &[authority_bump]]],
+                &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
             ),
-            num_tokens_rewarded.saturating_mul(SUBUNITS_PER_REWARD_TOKEN),
+            amount_transferred,
         )?;
 
         Ok(())
     }
 
-    pub fn extend(ctx: Context<Extend>, lock_duration_in_days: u64) -> Result<()> {
-        let clock = Clock::get()?;
-        let state = &mut ctx.accounts.state.load_mut()?;
-        let staker = &mut ctx.accounts.staker.load_mut()?;
-        require!(
-            ctx.accounts.user.key() == staker.staker_id,
-            StakerIdMismatch
-        );
-        let locked_nft = &mut ctx.accounts.locked_nft.load_mut()?;
-        let max_num_days_may_be_extended =
-            locked_nft.max_num_days_may_be_extended(clock.unix_timestamp);
-        require!(
-            lock_duration_in_days >= MIN_LOCK_DURATION_DAYS,
-            LockDurationTooSmall
-        );
+    /// Create a M-of-N multisig. Its signer PDA may be proposed as the admin of a pool, after
+    /// which admin instructions are proposed as multisig transactions and executed once approved
+    /// by `threshold` signers. The signer PDA must hold enough SOL to pay for any accounts created
+    /// by the instructions it executes.
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u64,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.set_signers(signers, threshold)?;
+        multisig.signer_bump = *ctx.bumps.get("multisig_signer").unwrap();
+
+        Ok(())
+    }
+
+    /// Replace the signers and threshold of a multisig. May only be called by the multisig
+    /// itself through an executed multisig transaction. Transactions proposed beforehand may no
+    /// longer be approved nor executed.
+    pub fn set_multisig_signers(
+        ctx: Context<SetMultisigSigners>,
+        signers: Vec<Pubkey>,
+        threshold: u64,
+    ) -> Result<()> {
+        ctx.accounts.multisig.set_signers(signers, threshold)?;
+
+        Ok(())
+    }
+
+    /// Propose an instruction to be executed with the multisig's signer PDA as a signer.
+    /// `transaction_id` must be the number of transactions proposed to the multisig so far. The
+    /// proposal counts as the proposer's approval.
+    pub fn propose_multisig_transaction(
+        ctx: Context<ProposeMultisigTransaction>,
+        transaction_id: u64,
+        instruction_program_id: Pubkey,
+        instruction_accounts: Vec<MultisigTransactionAccount>,
+        instruction_data: Vec<u8>,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
         require!(
-            lock_duration_in_days <= max_num_days_may_be_extended,
-            MaxPossibleLockDurationExceeded
+            transaction_id == multisig.num_transactions,
+            UnexpectedMultisigTransactionId
         );
+        let signer_index = multisig.signer_index(ctx.accounts.proposer.key())?;
 
-        state.update(clock.unix_timestamp);
-        staker.update(state, clock.unix_timestamp);
+        multisig.num_transactions += 1;
 
-        staker.mint_venfts(state, clock.unix_timestamp, lock_duration_in_days)?;
-        locked_nft.extend_lock_duration(clock.unix_timestamp, lock_duration_in_days);
+        let transaction = &mut ctx.accounts.multisig_transaction;
+        transaction.multisig_id = multisig.key();
+        transaction.transaction_id = transaction_id;
+        transaction.program_id = instruction_program_id;
+        transaction.accounts = instruction_accounts;
+        transaction.data = instruction_data;
+        transaction.approvals = vec![false; multisig.signers.len()];
+        transaction.signers_seqno = multisig.signers_seqno;
+        transaction.approve(multisig, signer_index)?;
 
-        drop(state);
-        drop(staker);
-        drop(locked_nft);
+        Ok(())
+    }
+
+    pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let signer_index = multisig.signer_index(ctx.accounts.signer.key())?;
+
+        ctx.accounts
+            .multisig_transaction
+            .approve(multisig, signer_index)?;
+
+        Ok(())
+    }
+
+    /// Execute a multisig transaction approved by at least as many signers as the multisig's
+    /// threshold. Anyone may execute an approved transaction. The accounts referenced by the
+    /// transaction, along with the program it invokes, are passed as remaining accounts.
+    pub fn execute_multisig_transaction(ctx: Context<ExecuteMultisigTransaction>) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let transaction = &mut ctx.accounts.multisig_transaction;
+        transaction.assert_executable(multisig)?;
+        transaction.executed = true;
+
+        let multisig_signer_id = ctx.accounts.multisig_signer.key();
+        let instruction = solana_program::instruction::Instruction {
+            program_id: transaction.program_id,
+            accounts: transaction
+                .accounts
+                .iter()
+                .map(|account| solana_program::instruction::AccountMeta {
+                    pubkey: account.pubkey,
+                    is_signer: account.is_signer || account.pubkey == multisig_signer_id,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: transaction.data.clone(),
+        };
+
+        let multisig_id = multisig.key();
+
+        solana_program::program::invoke_signed(
+            &instruction,
+            ctx.remaining_accounts,
+            &[&[
+                b"multisig_signer",
+                multisig_id.as_ref(),
+                &[multisig.signer_bump],
+            ]],
+        )?;
 
         Ok(())
     }
 
-    pub fn unstake(ctx: Context<Unstake>, authority_bump: u8) -> Result<()> {
+    /// Retire the pool. No NFTs may be staked nor locks extended afterwards, and any escrowed NFT
+    /// may be returned to its staker through `return_nft` regardless of its lock duration.
+    pub fn sunset(ctx: Context<AdminOnly>) -> Result<()> {
         let clock = Clock::get()?;
 
-        let locked_nft = ctx.accounts.locked_nft.load()?;
         let state = &mut ctx.accounts.state.load_mut()?;
-        let staker = &mut ctx.accounts.staker.load_mut()?;
-        require!(
-            ctx.accounts.user.key() == staker.staker_id,
-            StakerIdMismatch,
-        );
+        state.assert_not_timelocked()?;
+        state.sunset(clock.unix_timestamp)?;
 
-        require!(
-            locked_nft.may_be_unlocked(
-                ctx.accounts.user.key(),
-                ctx.accounts.nft_mint.key(),
-                clock.unix_timestamp,
-            ),
-            NotYetUnlockable
-        );
+        Ok(())
+    }
+
+    /// Choose what happens to rewards emitted on days on which no veNFT's are in circulation. See
+    /// the `ZERO_SUPPLY_REWARDS_*` policies.
+    pub fn set_zero_supply_rewards_policy(
+        ctx: Context<SetZeroSupplyRewardsPolicy>,
+        zero_supply_rewards_policy: u64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        let state = &mut ctx.accounts.state.load_mut()?;
+        state.assert_not_timelocked()?;
 
-        state.update(clock.unix_timestamp);
-        staker.update(state, clock.unix_timestamp);
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
+        state.set_zero_supply_rewards_policy(
+            &reward_schedule,
+            clock.unix_timestamp,
+            zero_supply_rewards_policy,
+        )?;
+
+        Ok(())
+    }
+
+    /// Set the percentage, in basis points, of their accrued rewards which stakers forfeit when
+    /// unstaking a NFT early, and where forfeited rewards go. See the `EARLY_UNSTAKE_PENALTY_TO_*`
+    /// destinations.
+    pub fn set_early_unstake_penalty(
+        ctx: Context<AdminOnly>,
+        early_unstake_penalty_bps: u64,
+        early_unstake_penalty_destination: u64,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state.load_mut()?;
+        state.assert_not_timelocked()?;
+        state.set_early_unstake_penalty(
+            early_unstake_penalty_bps,
+            early_unstake_penalty_destination,
+        )?;
 
-        state.total_num_locked_nfts = state.total_num_locked_nfts.saturating_sub(1);
-        staker.num_locked_nfts = staker.num_locked_nfts.saturating_sub(1);
+        Ok(())
+    }
+
+    /// Transfer rewards set aside under the `ZERO_SUPPLY_REWARDS_RECLAIMABLE` policy, and rewards
+    /// forfeited to the treasury by stakers unstaking early, from the treasury to `destination`.
+    pub fn reclaim_rewards(ctx: Context<ReclaimRewards>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        let mut state = ctx.accounts.state.load_mut()?;
+        state.assert_not_timelocked()?;
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
+
+        state.update(&reward_schedule, clock.unix_timestamp);
+        let amount_reclaimed = state.reclaim_rewards()?;
 
         drop(state);
-        drop(staker);
+
+        let state_id = ctx.accounts.state.key();
+        let authority_bump = ctx.accounts.authority.bump;
 
         anchor_spl::token::transfer(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 anchor_spl::token::Transfer {
-                    from: ctx.accounts.nft_escrow.to_account_info(),
-                    to: ctx.accounts.nft.to_account_info(),
+                    from: ctx.accounts.reward_token_treasury.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
                     authority: ctx.accounts.authority.to_account_info(),
                 },
-                &[&[b"authority", &[authority_bump]]],
+                &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
             ),
-            1,
+            amount_reclaimed,
         )?;
 
-        anchor_spl::token::close_account(CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::CloseAccount {
-                account: ctx.accounts.nft_escrow.to_account_info(),
-                destination: ctx.accounts.user.to_account_info(),
-                authority: ctx.accounts.authority.to_account_info(),
-            },
-            &[&[b"authority", &[authority_bump]]],
-        ))?;
-
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    #[account(zero)]
-    pub state: AccountLoader<'info, State>,
-    #[account(init, payer = admin, seeds = [b"authority"], bump)]
-    pub authority: Account<'info, Authority>,
-    pub reward_token_mint: Box<Account<'info, Mint>>,
-    #[account(init, payer = admin, seeds = [b"treasury"], bump, token::mint = reward_token_mint, token::authority = authority)]
-    pub reward_token_treasury: Box<Account<'info, TokenAccount>>,
+    /// Record the number of reward tokens emitted to stakers once reward emissions have ended and
+    /// the grace period after them has passed. Anyone may finalize the pool.
+    pub fn finalize(ctx: Context<Finalize>) -> Result<()> {
+        let clock = Clock::get()?;
 
-    pub rent: Sysvar<'info, Rent>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-}
+        let state = &mut ctx.accounts.state.load_mut()?;
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
+        state.finalize(&reward_schedule, clock.unix_timestamp)?;
 
-#[derive(Accounts)]
-pub struct Stake<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(mut, constraint = authority.state_id == state.key())]
-    pub state: AccountLoader<'info, State>,
-    #[account(mut, seeds = [b"authority"], bump)]
-    pub authority: Account<'info, Authority>,
-    pub nft_mint: Box<Account<'info, Mint>>,
-    #[account(owner = mpl_token_metadata::id(), seeds = [mpl_token_metadata::state::PREFIX.as_bytes(), mpl_token_metadata::id().as_ref(), nft_mint.key().as_ref()], bump, seeds::program = mpl_token_metadata::id())]
-    pub nft_metadata: AccountInfo<'info>,
-    #[account(mut, associated_token::mint = nft_mint, associated_token::authority = user)]
-    pub nft: Box<Account<'info, TokenAccount>>,
-    #[account(init, payer = user, seeds = [b"escrow", nft_mint.key().as_ref()], bump, token::mint = nft_mint, token::authority = authority)]
-    pub nft_escrow: Box<Account<'info, TokenAccount>>,
-    #[account(init_if_needed, payer = user, seeds = [b"staker", user.key().as_ref()], bump)]
-    pub staker: AccountLoader<'info, Staker>,
-    #[account(init, payer = user, seeds = [b"locked_nft", nft_mint.key().as_ref()], bump)]
-    pub locked_nft: AccountLoader<'info, LockedNft>,
+        Ok(())
+    }
 
-    pub rent: Sysvar<'info, Rent>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-}
+    /// Transfer whatever the treasury holds in excess of the rewards still owed to stakers to
+    /// `destination` after the pool has been finalized. Rounding dust left over from splitting
+    /// rewards amongst stakers stays in the treasury.
+    pub fn sweep_treasury(ctx: Context<SweepTreasury>) -> Result<()> {
+        let state = ctx.accounts.state.load()?;
+        state.assert_not_timelocked()?;
+        let surplus = state.treasury_surplus(ctx.accounts.reward_token_treasury.amount)?;
 
-#[derive(Accounts)]
-#[instruction(authority_bump: u8)]
-pub struct ClaimRewards<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(mut, constraint = authority.state_id == state.key())]
-    pub state: AccountLoader<'info, State>,
-    #[account(seeds = [b"authority"], bump = authority_bump)]
-    pub authority: Account<'info, Authority>,
-    #[account(mut, seeds = [b"staker", user.key().as_ref()], bump)]
-    pub staker: AccountLoader<'info, Staker>,
-    pub reward_token_mint: Box<Account<'info, Mint>>,
-    #[account(init_if_needed, payer = user, associated_token::mint = reward_token_mint, associated_token::authority = user)]
-    pub reward_token: Box<Account<'info, TokenAccount>>,
-    #[account(mut, seeds = [b"treasury"], bump)]
-    pub reward_token_treasury: Box<Account<'info, TokenAccount>>,
+        drop(state);
 
-    pub rent: Sysvar<'info, Rent>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-}
+        let state_id = ctx.accounts.state.key();
+        let authority_bump = ctx.accounts.authority.bump;
 
-#[derive(Accounts)]
-#[instruction(authority_bump: u8)]
-pub struct Unstake<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(mut, constraint = authority.state_id == state.key())]
-    pub state: AccountLoader<'info, State>,
-    #[account(seeds = [b"authority"], bump = authority_bump)]
-    pub authority: Account<'info, Authority>,
-    pub nft_mint: Box<Account<'info, Mint>>,
-    #[account(mut, associated_token::mint = nft_mint, associated_token::authority = user)]
-    pub nft: Box<Account<'info, TokenAccount>>,
-    #[account(mut, seeds = [b"escrow", nft_mint.key().as_ref()], bump)]
-    pub nft_escrow: Box<Account<'info, TokenAccount>>,
-    #[account(mut, seeds = [b"staker", user.key().as_ref()], bump)]
-    pub staker: AccountLoader<'info, Staker>,
-    #[account(mut, close = user, seeds = [b"locked_nft", nft_mint.key().as_ref()], bump)]
-    pub locked_nft: AccountLoader<'info, LockedNft>,
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.reward_token_treasury.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+                &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
+            ),
+            surplus,
+        )?;
 
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-}
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct Extend<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(mut, constraint = authority.state_id == state.key())]
-    pub state: AccountLoader<'info, State>,
-    #[account(seeds = [b"authority"], bump)]
-    pub authority: Account<'info, Authority>,
-    pub nft_mint: Box<Account<'info, Mint>>,
-    #[account(mut, seeds = [b"staker", user.key().as_ref()], bump)]
-    pub staker: AccountLoader<'info, Staker>,
-    #[account(mut, seeds = [b"locked_nft", nft_mint.key().as_ref()], bump)]
-    pub locked_nft: AccountLoader<'info, LockedNft>,
-}
+    /// Allow NFTs which list `address` as one of their creators to be staked. If the creator is
+    /// already allowed, only whether or not it must be verified is updated.
+    pub fn add_allowed_creator(
+        ctx: Context<UpdatePoolConfig>,
+        address: Pubkey,
+        must_be_verified: bool,
+    ) -> Result<()> {
+        ctx.accounts.state.load()?.assert_not_timelocked()?;
+        ctx.accounts
+            .pool_config
+            .add_allowed_creator(address, must_be_verified)?;
 
-pub fn assert_lockable_nft(mint_id: Pubkey, metadata_account: &AccountInfo) -> Result<()> {
-    use std::str::FromStr;
+        Ok(())
+    }
 
-    let metadata = mpl_token_metadata::state::Metadata::from_account_info(metadata_account)?;
+    pub fn remove_allowed_creator(ctx: Context<UpdatePoolConfig>, address: Pubkey) -> Result<()> {
+        ctx.accounts.state.load()?.assert_not_timelocked()?;
+        ctx.accounts.pool_config.remove_allowed_creator(address)?;
 
-    require!(metadata.mint == mint_id, MetadataMintMismatch,);
+        Ok(())
+    }
+
+    /// Allow NFTs which belong to the verified Metaplex collection `collection_mint_id` to be
+    /// staked.
+    pub fn add_allowed_collection(
+        ctx: Context<UpdatePoolConfig>,
+        collection_mint_id: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.state.load()?.assert_not_timelocked()?;
+        ctx.accounts
+            .pool_config
+            .add_allowed_collection(collection_mint_id)?;
+
+        Ok(())
+    }
+
+    pub fn remove_allowed_collection(
+        ctx: Context<UpdatePoolConfig>,
+        collection_mint_id: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.state.load()?.assert_not_timelocked()?;
+        ctx.accounts
+            .pool_config
+            .remove_allowed_collection(collection_mint_id)?;
+
+        Ok(())
+    }
+
+    /// Write the number of reward tokens emitted per day for a chunk of days starting at
+    /// `day_offset`. The reward schedule is written over several transactions before it is
+    /// sealed.
+    pub fn write_reward_schedule(
+        ctx: Context<UpdateRewardSchedule>,
+        day_offset: u64,
+        reward_per_day: Vec<u64>,
+    ) -> Result<()> {
+        ctx.accounts.state.load()?.assert_not_timelocked()?;
+
+        let reward_schedule = &mut ctx.accounts.reward_schedule.load_mut()?;
+        reward_schedule.write(day_offset, &reward_per_day)?;
+
+        Ok(())
+    }
+
+    /// Have the reward schedule compute emissions from a parametric curve rather than from an
+    /// uploaded table.
+    pub fn set_emission_curve(
+        ctx: Context<UpdateRewardSchedule>,
+        emission_curve: EmissionCurve,
+    ) -> Result<()> {
+        ctx.accounts.state.load()?.assert_not_timelocked()?;
+
+        let reward_schedule = &mut ctx.accounts.reward_schedule.load_mut()?;
+        reward_schedule.set_emission_curve(emission_curve)?;
+
+        Ok(())
+    }
+
+    /// Seal the reward schedule, making it immutable and allowing NFTs to start being staked.
+    pub fn seal_reward_schedule(
+        ctx: Context<UpdateRewardSchedule>,
+        total_reward_emission: u64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        ctx.accounts.state.load()?.assert_not_timelocked()?;
+
+        let reward_schedule = &mut ctx.accounts.reward_schedule.load_mut()?;
+        reward_schedule.seal(total_reward_emission, clock.unix_timestamp)?;
+
+        Ok(())
+    }
+
+    pub fn set_nft_gating_mode(
+        ctx: Context<UpdatePoolConfig>,
+        nft_gating_mode: NftGatingMode,
+    ) -> Result<()> {
+        ctx.accounts.state.load()?.assert_not_timelocked()?;
+        ctx.accounts.pool_config.nft_gating_mode = nft_gating_mode;
+
+        Ok(())
+    }
+
+    pub fn stake(ctx: Context<Stake>, lock_duration_in_days: u64) -> Result<()> {
+        let event = process_stake(
+            StakeAccounts {
+                state: &ctx.accounts.state,
+                reward_schedule: &ctx.accounts.reward_schedule,
+                pool_config: &ctx.accounts.pool_config,
+                nft_mint: &ctx.accounts.nft_mint,
+                nft_metadata: &ctx.accounts.nft_metadata,
+                staker: &ctx.accounts.staker,
+                locked_nft: &ctx.accounts.locked_nft,
+            },
+            &ctx.bumps,
+            ctx.accounts.user.key(),
+            lock_duration_in_days,
+        )?;
+
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.nft.to_account_info(),
+                    to: ctx.accounts.nft_escrow.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        emit!(event);
+
+        Ok(())
+    }
+
+    /// Stake a NFT held by the depositor on behalf of a beneficiary. The NFT is locked into the
+    /// beneficiary's staker account, and rewards and the right to unstake the NFT go to the
+    /// beneficiary.
+    pub fn stake_for(ctx: Context<StakeFor>, lock_duration_in_days: u64) -> Result<()> {
+        let event = process_stake(
+            StakeAccounts {
+                state: &ctx.accounts.state,
+                reward_schedule: &ctx.accounts.reward_schedule,
+                pool_config: &ctx.accounts.pool_config,
+                nft_mint: &ctx.accounts.nft_mint,
+                nft_metadata: &ctx.accounts.nft_metadata,
+                staker: &ctx.accounts.staker,
+                locked_nft: &ctx.accounts.locked_nft,
+            },
+            &ctx.bumps,
+            ctx.accounts.beneficiary.key(),
+            lock_duration_in_days,
+        )?;
+
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.nft.to_account_info(),
+                    to: ctx.accounts.nft_escrow.to_account_info(),
+                    authority: ctx.accounts.depositor.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        emit!(event);
+
+        Ok(())
+    }
+
+    /// Stake many NFTs at once. NFTs are supplied through the remaining accounts in groups of
+    /// `STAKE_MANY_ACCOUNTS_PER_NFT` accounts. Either a single lock duration shared by every NFT,
+    /// or one lock duration per NFT is to be provided.
+    pub fn stake_many<'info>(
+        ctx: Context<'_, '_, '_, 'info, StakeMany<'info>>,
+        lock_durations_in_days: Vec<u64>,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        let state_id = ctx.accounts.state.key();
+        let user_id = ctx.accounts.user.key();
 
-    match metadata.data.creators {
-        Some(creators) => {
-            let creator = match creators.iter().next() {
-                Some(creator) => creator,
-                None => return Err(ErrorCode::MetadataHasNoCreators.into()),
+        let account_ids: Vec<Pubkey> = ctx.remaining_accounts.iter().map(|a| a.key()).collect();
+        let bumps = validate_stake_many_accounts(ctx.program_id, state_id, user_id, &account_ids)?;
+        let num_nfts = bumps.len();
+        require!(
+            lock_durations_in_days.len() == 1 || lock_durations_in_days.len() == num_nfts,
+            NumLockDurationsMismatch
+        );
+
+        let state = ctx.accounts.state.load()?;
+        state.assert_not_paused(PAUSE_STAKE)?;
+        state.assert_not_sunset()?;
+        drop(state);
+
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
+        require!(reward_schedule.is_sealed(), RewardScheduleNotSealed);
+        drop(reward_schedule);
+
+        // First, every NFT is checked to be lockable and moved into a newly created escrow, and
+        // an account is created to record its lock.
+
+        let mut locked_nfts = Vec::with_capacity(num_nfts);
+
+        for (group, bumps) in ctx
+            .remaining_accounts
+            .chunks(STAKE_MANY_ACCOUNTS_PER_NFT)
+            .zip(bumps)
+        {
+            let (nft_mint, nft_metadata, nft, nft_escrow, locked_nft) = match group {
+                [nft_mint, nft_metadata, nft, nft_escrow, locked_nft] => {
+                    (nft_mint, nft_metadata, nft, nft_escrow, locked_nft)
+                }
+                _ => return err!(InvalidRemainingAccounts),
             };
 
+            let nft_mint = Account::<Mint>::try_from(nft_mint)?;
+            let mint_id = nft_mint.key();
+
             require!(
-                creator.address
-                    == Pubkey::from_str("7QAB4Y3xGKtyV71qfasjbk1qXkZTTuEuR8WjsM2PijW5").unwrap(),
-                UnexpectedMetadataCreator
+                *nft_metadata.owner == mpl_token_metadata::id(),
+                InvalidRemainingAccounts
             );
+            assert_lockable_nft(mint_id, nft_metadata, &ctx.accounts.pool_config)?;
+
+            let StakeManyBumps {
+                escrow_bump,
+                locked_nft_bump,
+            } = bumps;
+
+            create_pda_account(
+                &ctx.accounts.user,
+                nft_escrow,
+                &ctx.accounts.system_program,
+                TokenAccount::LEN,
+                &anchor_spl::token::ID,
+                &[
+                    b"escrow",
+                    state_id.as_ref(),
+                    mint_id.as_ref(),
+                    &[escrow_bump],
+                ],
+            )?;
+            anchor_spl::token::initialize_account(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::InitializeAccount {
+                    account: nft_escrow.clone(),
+                    mint: nft_mint.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+            ))?;
+            anchor_spl::token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    anchor_spl::token::Transfer {
+                        from: nft.clone(),
+                        to: nft_escrow.clone(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                ),
+                1,
+            )?;
+
+            create_pda_account(
+                &ctx.accounts.user,
+                locked_nft,
+                &ctx.accounts.system_program,
+                8 + std::mem::size_of::<LockedNft>(),
+                ctx.program_id,
+                &[
+                    b"locked_nft",
+                    state_id.as_ref(),
+                    mint_id.as_ref(),
+                    &[locked_nft_bump],
+                ],
+            )?;
+
+            locked_nfts.push((
+                mint_id,
+                AccountLoader::<LockedNft>::try_from_unchecked(ctx.program_id, locked_nft)?,
+                locked_nft_bump,
+                escrow_bump,
+            ));
+        }
+
+        // Second, the state and staker are updated once, and veNFT's are minted for every NFT.
+
+        let mut state = ctx.accounts.state.load_mut()?;
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
+        let mut staker = load_or_init_staker(
+            &ctx.accounts.staker,
+            user_id,
+            *ctx.bumps.get("staker").unwrap(),
+            clock.unix_timestamp,
+        )?;
 
-            // TODO: Uncomment metadata creator verification constraint on mainnet.
-            require!(true || creator.verified, MetadataCreatorUnverified);
+        state.update(&reward_schedule, clock.unix_timestamp);
+        staker.update(&state, clock.unix_timestamp);
+        staker.stake_nfts(&mut state, num_nfts as u64);
+
+        let mut events = Vec::with_capacity(num_nfts);
+
+        for (i, (mint_id, loader, bump, escrow_bump)) in locked_nfts.iter().enumerate() {
+            let lock_duration_in_days = lock_durations_in_days
+                .get(i)
+                .copied()
+                .unwrap_or(lock_durations_in_days[0]);
+
+            let locked_nft = &mut loader.load_init()?;
+            locked_nft.staker_id = user_id;
+            locked_nft.mint_id = *mint_id;
+            locked_nft.locked_at = clock.unix_timestamp;
+            locked_nft.lock_duration_in_days = lock_duration_in_days;
+            locked_nft.bump = *bump;
+            locked_nft.escrow_bump = *escrow_bump;
+
+            let venft_amount_minted = staker.mint_venfts(
+                &mut state,
+                locked_nft,
+                clock.unix_timestamp,
+                lock_duration_in_days,
+            )?;
+
+            events.push(NftStaked {
+                state_id,
+                staker_id: user_id,
+                mint_id: *mint_id,
+                lock_duration_in_days,
+                venft_amount_minted,
+                unlocks_at: locked_nft.unlocks_at(),
+                staker_num_locked_nfts: staker.num_locked_nfts,
+                total_num_locked_nfts: state.total_num_locked_nfts,
+            });
         }
-        None => return Err(ErrorCode::MetadataHasNoCreators.into()),
-    };
 
-    Ok(())
-}
+        drop(state);
+        drop(staker);
 
-#[account]
-#[derive(Default)]
-pub struct Authority {
-    pub state_id: Pubkey,
-}
+        for (_, loader, _, _) in locked_nfts.iter() {
+            loader.exit(ctx.program_id)?;
+        }
 
-#[account(zero_copy)]
-#[derive(Default, Debug)]
-pub struct LockedNft {
-    pub staker_id: Pubkey,
-    pub mint_id: Pubkey,
+        for event in events {
+            emit!(event);
+        }
 
-    pub locked_at: i64,
-    pub lock_duration_in_days: u64,
-}
+        Ok(())
+    }
 
-impl LockedNft {
-    pub fn max_num_days_may_be_extended(&self, current_time: i64) -> u64 {
-        let days_elapsed_since_locked = days_between_timestamps(self.locked_at, current_time);
-        let days_left_before_unlocked = self
-            .lock_duration_in_days
-            .saturating_sub(days_elapsed_since_locked);
-        MAX_LOCK_DURATION_DAYS.saturating_sub(days_left_before_unlocked)
+    /// Claim all rewards accrued by the staker. Fails should the treasury not hold enough reward
+    /// tokens to pay them out in full.
+    pub fn claim_rewards_v2(ctx: Context<ClaimRewards>) -> Result<()> {
+        process_claim_rewards(ctx, false)
     }
 
-    pub fn extend_lock_duration(&mut self, current_time: i64, lock_duration_in_days: u64) {
-        let days_elapsed_since_locked = days_between_timestamps(self.locked_at, current_time);
-        let days_left_before_unlocked = self
-            .lock_duration_in_days
-            .saturating_sub(days_elapsed_since_locked);
-        self.locked_at = current_time;
-        self.lock_duration_in_days =
-            days_left_before_unlocked.saturating_add(lock_duration_in_days);
+    /// Claim as many of the rewards accrued by the staker as the treasury is able to pay out.
+    /// Rewards which could not be paid out remain claimable.
+    pub fn claim_available_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        process_claim_rewards(ctx, true)
     }
 
-    pub fn may_be_unlocked(&self, user_id: Pubkey, mint_id: Pubkey, current_time: i64) -> bool {
-        self.staker_id == user_id
-            && self.mint_id == mint_id
-            && current_time
-                > self.locked_at.saturating_add(
-                    self.lock_duration_in_days
-                        .saturating_mul(24 * 60 * 60)
-                        .try_into()
-                        .unwrap_or(i64::MAX),
-                )
+    /// Deposit reward tokens into the treasury. Anyone may fund the treasury.
+    pub fn fund_treasury(ctx: Context<FundTreasury>, amount: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.funder_reward_token.to_account_info(),
+                    to: ctx.accounts.reward_token_treasury.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let state = &mut ctx.accounts.state.load_mut()?;
+        state.total_rewards_funded = state.total_rewards_funded.saturating_add(amount);
+
+        Ok(())
     }
-}
 
-#[account(zero_copy)]
-#[derive(Debug)]
-pub struct State {
-    pub admin_id: Pubkey,
+    pub fn extend(ctx: Context<Extend>, lock_duration_in_days: u64) -> Result<()> {
+        let clock = Clock::get()?;
+        let mut state = ctx.accounts.state.load_mut()?;
+        state.assert_not_paused(PAUSE_EXTEND)?;
+        state.assert_not_sunset()?;
+        let mut staker = ctx.accounts.staker.load_mut()?;
+        require!(
+            ctx.accounts.user.key() == staker.staker_id,
+            StakerIdMismatch
+        );
+        let mut locked_nft = ctx.accounts.locked_nft.load_mut()?;
+        require!(!locked_nft.is_perpetual(), PerpetuallyLocked);
+        let max_num_days_may_be_extended = locked_nft
+            .max_num_days_may_be_extended(clock.unix_timestamp, state.max_lock_duration_in_days);
+        require!(
+            lock_duration_in_days >= state.min_lock_duration_in_days,
+            LockDurationTooSmall
+        );
+        require!(
+            lock_duration_in_days <= max_num_days_may_be_extended,
+            MaxPossibleLockDurationExceeded
+        );
 
-    pub reward_token_mint_id: Pubkey,
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
 
-    pub deployed_at: i64,
-    pub last_updated_at: i64,
+        state.update(&reward_schedule, clock.unix_timestamp);
+        staker.update(&state, clock.unix_timestamp);
 
-    pub total_num_locked_nfts: u64,
+        let venft_amount_minted = staker.relock_venfts(
+            &mut state,
+            &mut locked_nft,
+            clock.unix_timestamp,
+            lock_duration_in_days,
+        )?;
 
-    /// Index 0 starts on the first day after the program is deployed.
-    pub venft_supply: [u64; 1461],
-}
+        emit!(LockExtended {
+            state_id: ctx.accounts.state.key(),
+            staker_id: staker.staker_id,
+            mint_id: ctx.accounts.nft_mint.key(),
+            num_days_extended: lock_duration_in_days,
+            lock_duration_in_days: locked_nft.lock_duration_in_days,
+            venft_amount_minted,
+            unlocks_at: locked_nft.unlocks_at(),
+            staker_num_locked_nfts: staker.num_locked_nfts,
+            total_num_locked_nfts: state.total_num_locked_nfts,
+        });
 
-impl Default for State {
-    fn default() -> Self {
-        Self {
-            admin_id: Default::default(),
+        drop(state);
+        drop(staker);
+        drop(locked_nft);
 
-            reward_token_mint_id: Default::default(),
+        Ok(())
+    }
 
-            deployed_at: Default::default(),
-            last_updated_at: Default::default(),
+    /// Toggle whether a NFT is perpetually locked. A perpetually locked NFT counts as locked for
+    /// the pool's maximum lock duration at the time it was locked every day without its veNFT's
+    /// decaying. Once no longer
+    /// perpetually locked, the NFT is locked for the pool's maximum lock duration starting from
+    /// the current day.
+    pub fn set_perpetual_lock(ctx: Context<Extend>, perpetual: bool) -> Result<()> {
+        let clock = Clock::get()?;
+        let mut state = ctx.accounts.state.load_mut()?;
+        if perpetual {
+            state.assert_not_paused(PAUSE_EXTEND)?;
+            state.assert_not_sunset()?;
+        }
+        let mut staker = ctx.accounts.staker.load_mut()?;
+        require!(
+            ctx.accounts.user.key() == staker.staker_id,
+            StakerIdMismatch
+        );
+        let mut locked_nft = ctx.accounts.locked_nft.load_mut()?;
+        require!(locked_nft.staker_id == staker.staker_id, StakerIdMismatch);
 
-            total_num_locked_nfts: Default::default(),
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
 
-            venft_supply: [0u64; MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize],
+        state.update(&reward_schedule, clock.unix_timestamp);
+        staker.update(&state, clock.unix_timestamp);
+
+        let venft_amount = match perpetual {
+            true => staker.lock_perpetually(&mut state, &mut locked_nft, clock.unix_timestamp)?,
+            false => {
+                staker.unlock_perpetually(&mut state, &mut locked_nft, clock.unix_timestamp)?
+            }
+        };
+
+        emit!(PerpetualLockSet {
+            state_id: ctx.accounts.state.key(),
+            staker_id: staker.staker_id,
+            mint_id: locked_nft.mint_id,
+            perpetual,
+            venft_amount,
+            unlocks_at: locked_nft.unlocks_at(),
+        });
+
+        Ok(())
+    }
+
+    /// Extend the lock of every NFT supplied through the remaining accounts by their locked NFT
+    /// accounts as far as the pool allows. A `RelockResult` for each NFT is returned in the order
+    /// they were supplied, with NFTs which may not be extended any further left untouched.
+    pub fn extend_all_to_max<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExtendAllToMax<'info>>,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(!ctx.remaining_accounts.is_empty(), InvalidRemainingAccounts);
+
+        let state_id = ctx.accounts.state.key();
+        let user_id = ctx.accounts.user.key();
+
+        let mut state = ctx.accounts.state.load_mut()?;
+        state.assert_not_paused(PAUSE_EXTEND)?;
+        state.assert_not_sunset()?;
+        let mut staker = ctx.accounts.staker.load_mut()?;
+        require!(user_id == staker.staker_id, StakerIdMismatch);
+
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
+
+        state.update(&reward_schedule, clock.unix_timestamp);
+        staker.update(&state, clock.unix_timestamp);
+
+        let mut results = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut events = Vec::new();
+
+        for locked_nft in ctx.remaining_accounts {
+            let loader = AccountLoader::<LockedNft>::try_from(locked_nft)?;
+            let mut locked_nft = loader.load_mut()?;
+
+            require!(
+                Pubkey::create_program_address(
+                    &[
+                        b"locked_nft",
+                        state_id.as_ref(),
+                        locked_nft.mint_id.as_ref(),
+                        &[locked_nft.bump],
+                    ],
+                    ctx.program_id,
+                ) == Ok(loader.key()),
+                InvalidRemainingAccounts
+            );
+            require!(locked_nft.staker_id == user_id, StakerIdMismatch);
+
+            let num_days_extended =
+                state.max_num_days_may_be_extended(&locked_nft, clock.unix_timestamp);
+            if num_days_extended == 0 {
+                results.push(RelockResult::default());
+                continue;
+            }
+
+            let venft_amount_minted = staker.relock_venfts(
+                &mut state,
+                &mut locked_nft,
+                clock.unix_timestamp,
+                num_days_extended,
+            )?;
+
+            results.push(RelockResult {
+                num_days_extended,
+                venft_amount_minted,
+            });
+            events.push(LockExtended {
+                state_id,
+                staker_id: user_id,
+                mint_id: locked_nft.mint_id,
+                num_days_extended,
+                lock_duration_in_days: locked_nft.lock_duration_in_days,
+                venft_amount_minted,
+                unlocks_at: locked_nft.unlocks_at(),
+                staker_num_locked_nfts: staker.num_locked_nfts,
+                total_num_locked_nfts: state.total_num_locked_nfts,
+            });
+        }
+
+        drop(state);
+        drop(staker);
+
+        anchor_lang::solana_program::program::set_return_data(&results.try_to_vec()?);
+
+        for event in events {
+            emit!(event);
         }
+
+        Ok(())
     }
-}
 
-impl State {
-    pub fn update(&mut self, current_time: i64) {
-        self.last_updated_at = current_time;
+    pub fn unstake_v2(ctx: Context<Unstake>) -> Result<()> {
+        process_unstake(ctx, false)
     }
-}
 
-#[account(zero_copy)]
-#[derive(Debug)]
-pub struct Staker {
-    pub staker_id: Pubkey,
+    /// Unstake every NFT supplied through the remaining accounts in groups of
+    /// `UNSTAKE_MANY_ACCOUNTS_PER_NFT` accounts whose lock has elapsed. NFTs which may not be
+    /// unlocked yet are skipped, and reported through `NftUnstakeSkipped` events.
+    pub fn unstake_many<'info>(ctx: Context<'_, '_, '_, 'info, UnstakeMany<'info>>) -> Result<()> {
+        let clock = Clock::get()?;
 
-    /// Index 0 starts from the last day the staker was last updated.
-    pub venft_balance: [u64; 183],
+        require!(
+            !ctx.remaining_accounts.is_empty()
+                && ctx.remaining_accounts.len() % UNSTAKE_MANY_ACCOUNTS_PER_NFT == 0,
+            InvalidRemainingAccounts
+        );
 
-    pub num_locked_nfts: u64,
-    pub num_rewards_claimable: u64,
+        let state_id = ctx.accounts.state.key();
+        let user_id = ctx.accounts.user.key();
+
+        let mut state = ctx.accounts.state.load_mut()?;
+        let mut staker = ctx.accounts.staker.load_mut()?;
+        require!(user_id == staker.staker_id, StakerIdMismatch);
+
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
+
+        state.update(&reward_schedule, clock.unix_timestamp);
+        staker.update(&state, clock.unix_timestamp);
+
+        let mut groups = Vec::new();
+
+        for group in ctx.remaining_accounts.chunks(UNSTAKE_MANY_ACCOUNTS_PER_NFT) {
+            let (nft_mint, nft, nft_escrow, locked_nft) = match group {
+                [nft_mint, nft, nft_escrow, locked_nft] => (nft_mint, nft, nft_escrow, locked_nft),
+                _ => return err!(InvalidRemainingAccounts),
+            };
+
+            let mint_id = nft_mint.key();
+            let loader = AccountLoader::<LockedNft>::try_from(locked_nft)?;
+            let locked_nft = loader.load()?;
+
+            require!(
+                Pubkey::create_program_address(
+                    &[
+                        b"locked_nft",
+                        state_id.as_ref(),
+                        mint_id.as_ref(),
+                        &[locked_nft.bump],
+                    ],
+                    ctx.program_id,
+                ) == Ok(loader.key())
+                    && Pubkey::create_program_address(
+                        &[
+                            b"escrow",
+                            state_id.as_ref(),
+                            mint_id.as_ref(),
+                            &[locked_nft.escrow_bump],
+                        ],
+                        ctx.program_id,
+                    ) == Ok(nft_escrow.key())
+                    && nft.key()
+                        == anchor_spl::associated_token::get_associated_token_address(
+                            &user_id, &mint_id
+                        ),
+                InvalidRemainingAccounts
+            );
+
+            drop(locked_nft);
+            groups.push((mint_id, loader, nft, nft_escrow));
+        }
+
+        let mut locked_nfts = groups
+            .iter()
+            .map(|(_, loader, _, _)| loader.load_mut())
+            .collect::<Result<Vec<_>>>()?;
+
+        let unstaked = staker.unstake_unlocked_nfts(
+            &mut state,
+            groups.iter().map(|(mint_id, _, _, _)| *mint_id).zip(
+                locked_nfts
+                    .iter_mut()
+                    .map(|locked_nft| locked_nft.deref_mut()),
+            ),
+            clock.unix_timestamp,
+        );
+
+        let skipped_events: Vec<NftUnstakeSkipped> = groups
+            .iter()
+            .zip(locked_nfts.iter())
+            .zip(unstaked.iter())
+            .filter(|(_, &unstaked)| !unstaked)
+            .map(|(((mint_id, _, _, _), locked_nft), _)| NftUnstakeSkipped {
+                state_id,
+                staker_id: user_id,
+                mint_id: *mint_id,
+                unlocks_at: locked_nft.unlocks_at(),
+            })
+            .collect();
+
+        drop(locked_nfts);
+
+        let unlocked_nfts: Vec<_> = groups
+            .into_iter()
+            .zip(unstaked)
+            .filter(|(_, unstaked)| *unstaked)
+            .map(|(group, _)| group)
+            .collect();
+
+        let unstaked_events: Vec<NftUnstaked> = unlocked_nfts
+            .iter()
+            .map(|(mint_id, _, _, _)| NftUnstaked {
+                state_id,
+                staker_id: user_id,
+                mint_id: *mint_id,
+                num_rewards_forfeited: 0,
+                staker_num_locked_nfts: staker.num_locked_nfts,
+                total_num_locked_nfts: state.total_num_locked_nfts,
+            })
+            .collect();
+
+        drop(state);
+        drop(staker);
+
+        let authority_bump = ctx.accounts.authority.bump;
+
+        for (_, loader, nft, nft_escrow) in unlocked_nfts {
+            anchor_spl::token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    anchor_spl::token::Transfer {
+                        from: nft_escrow.clone(),
+                        to: nft.clone(),
+                        authority: ctx.accounts.authority.to_account_info(),
+                    },
+                    &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
+                ),
+                1,
+            )?;
+
+            anchor_spl::token::close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::CloseAccount {
+                    account: nft_escrow.clone(),
+                    destination: ctx.accounts.user.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+                &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
+            ))?;
+
+            loader.close(ctx.accounts.user.to_account_info())?;
+        }
+
+        for event in unstaked_events {
+            emit!(event);
+        }
+        for event in skipped_events {
+            emit!(event);
+        }
+
+        Ok(())
+    }
+
+    /// Unstake a NFT before its lock has elapsed. veNFT's minted in exchange for the NFT from
+    /// the current day onwards are burned, and the staker forfeits a share of the rewards accrued
+    /// for the NFT which scales with the number of days the NFT remains locked for.
+    pub fn unstake_early(ctx: Context<Unstake>) -> Result<()> {
+        process_unstake(ctx, true)
+    }
+
+    /// Return an escrowed NFT to its staker once the pool has been sunset, irrespective of how
+    /// long the NFT remains locked for. Anyone may return an NFT on behalf of its staker. Rewards
+    /// accrued by the staker remain claimable.
+    pub fn return_nft(ctx: Context<ReturnNft>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        let mut locked_nft = ctx.accounts.locked_nft.load_mut()?;
+        let mut state = ctx.accounts.state.load_mut()?;
+        require!(state.is_sunset(), PoolNotSunset);
+        let mut staker = ctx.accounts.staker.load_mut()?;
+        require!(
+            ctx.accounts.staker_wallet.key() == staker.staker_id,
+            StakerIdMismatch
+        );
+        require!(
+            ctx.accounts.staker_wallet.key() == locked_nft.staker_id,
+            StakerIdMismatch
+        );
+
+        let reward_schedule = ctx.accounts.reward_schedule.load()?;
+
+        state.update(&reward_schedule, clock.unix_timestamp);
+        staker.update(&state, clock.unix_timestamp);
+        staker.burn_venfts(&mut state, &mut locked_nft, clock.unix_timestamp);
+
+        staker.unstake_nfts(&mut state, 1);
+
+        let event = NftUnstaked {
+            state_id: ctx.accounts.state.key(),
+            staker_id: staker.staker_id,
+            mint_id: locked_nft.mint_id,
+            num_rewards_forfeited: 0,
+            staker_num_locked_nfts: staker.num_locked_nfts,
+            total_num_locked_nfts: state.total_num_locked_nfts,
+        };
+
+        drop(locked_nft);
+        drop(state);
+        drop(staker);
+
+        let state_id = ctx.accounts.state.key();
+        let authority_bump = ctx.accounts.authority.bump;
+
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.nft_escrow.to_account_info(),
+                    to: ctx.accounts.nft.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+                &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
+            ),
+            1,
+        )?;
+
+        anchor_spl::token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::CloseAccount {
+                account: ctx.accounts.nft_escrow.to_account_info(),
+                destination: ctx.accounts.staker_wallet.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+            &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
+        ))?;
+
+        emit!(event);
+
+        Ok(())
+    }
+}
+
+/// Account loaders shared by `stake` and `stake_for`.
+struct StakeAccounts<'a, 'info> {
+    state: &'a AccountLoader<'info, State>,
+    reward_schedule: &'a AccountLoader<'info, RewardSchedule>,
+    pool_config: &'a PoolConfig,
+    nft_mint: &'a Account<'info, Mint>,
+    nft_metadata: &'a AccountInfo<'info>,
+    staker: &'a AccountLoader<'info, Staker>,
+    locked_nft: &'a AccountLoader<'info, LockedNft>,
+}
+
+/// Lock a NFT into the staker account of `staker_id`, and mint veNFT's to the staker in exchange.
+/// Moving the NFT into its escrow is left to the caller.
+fn process_stake(
+    accounts: StakeAccounts,
+    bumps: &std::collections::BTreeMap<String, u8>,
+    staker_id: Pubkey,
+    lock_duration_in_days: u64,
+) -> Result<NftStaked> {
+    let clock = Clock::get()?;
+
+    assert_lockable_nft(
+        accounts.nft_mint.key(),
+        accounts.nft_metadata,
+        accounts.pool_config,
+    )?;
+
+    let mut state = accounts.state.load_mut()?;
+    state.assert_not_paused(PAUSE_STAKE)?;
+    state.assert_not_sunset()?;
+
+    let reward_schedule = accounts.reward_schedule.load()?;
+    require!(reward_schedule.is_sealed(), RewardScheduleNotSealed);
+
+    let mut staker = load_or_init_staker(
+        accounts.staker,
+        staker_id,
+        bumps["staker"],
+        clock.unix_timestamp,
+    )?;
+
+    let locked_nft = &mut accounts.locked_nft.load_init()?;
+    locked_nft.staker_id = staker_id;
+    locked_nft.mint_id = accounts.nft_mint.key();
+    locked_nft.locked_at = clock.unix_timestamp;
+    locked_nft.lock_duration_in_days = lock_duration_in_days;
+    locked_nft.bump = bumps["locked_nft"];
+    locked_nft.escrow_bump = bumps["nft_escrow"];
+
+    state.update(&reward_schedule, clock.unix_timestamp);
+    staker.update(&state, clock.unix_timestamp);
+
+    staker.stake_nft(&mut state);
+    let venft_amount_minted = staker.mint_venfts(
+        &mut state,
+        locked_nft,
+        clock.unix_timestamp,
+        lock_duration_in_days,
+    )?;
+
+    Ok(NftStaked {
+        state_id: accounts.state.key(),
+        staker_id: staker.staker_id,
+        mint_id: locked_nft.mint_id,
+        lock_duration_in_days,
+        venft_amount_minted,
+        unlocks_at: locked_nft.unlocks_at(),
+        staker_num_locked_nfts: staker.num_locked_nfts,
+        total_num_locked_nfts: state.total_num_locked_nfts,
+    })
+}
+
+fn process_unstake(ctx: Context<Unstake>, early: bool) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let mut locked_nft = ctx.accounts.locked_nft.load_mut()?;
+    let mut state = ctx.accounts.state.load_mut()?;
+    let mut staker = ctx.accounts.staker.load_mut()?;
+    require!(
+        ctx.accounts.user.key() == staker.staker_id,
+        StakerIdMismatch,
+    );
+
+    if early {
+        require!(
+            locked_nft.staker_id == ctx.accounts.user.key()
+                && locked_nft.mint_id == ctx.accounts.nft_mint.key(),
+            StakerIdMismatch
+        );
+    } else {
+        require!(
+            locked_nft.may_be_unlocked(
+                ctx.accounts.user.key(),
+                ctx.accounts.nft_mint.key(),
+                clock.unix_timestamp,
+            ),
+            NotYetUnlockable
+        );
+    }
+
+    let reward_schedule = ctx.accounts.reward_schedule.load()?;
+
+    state.update(&reward_schedule, clock.unix_timestamp);
+    staker.update(&state, clock.unix_timestamp);
+    staker.burn_venfts(&mut state, &mut locked_nft, clock.unix_timestamp);
+
+    let num_rewards_forfeited = match early {
+        true => staker.forfeit_rewards(&mut state, &locked_nft, clock.unix_timestamp),
+        false => 0,
+    };
+
+    staker.unstake_nfts(&mut state, 1);
+
+    let event = NftUnstaked {
+        state_id: ctx.accounts.state.key(),
+        staker_id: staker.staker_id,
+        mint_id: locked_nft.mint_id,
+        num_rewards_forfeited,
+        staker_num_locked_nfts: staker.num_locked_nfts,
+        total_num_locked_nfts: state.total_num_locked_nfts,
+    };
+
+    drop(locked_nft);
+    drop(state);
+    drop(staker);
+
+    let state_id = ctx.accounts.state.key();
+    let authority_bump = ctx.accounts.authority.bump;
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.nft_escrow.to_account_info(),
+                to: ctx.accounts.nft.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+            &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
+        ),
+        1,
+    )?;
+
+    anchor_spl::token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        anchor_spl::token::CloseAccount {
+            account: ctx.accounts.nft_escrow.to_account_info(),
+            destination: ctx.accounts.user.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        },
+        &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
+    ))?;
+
+    emit!(event);
+
+    Ok(())
+}
+
+fn process_claim_rewards(ctx: Context<ClaimRewards>, allow_partial_claim: bool) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let mut state = ctx.accounts.state.load_mut()?;
+    state.assert_not_paused(PAUSE_CLAIM_REWARDS)?;
+    require!(
+        ctx.accounts.reward_token_mint.key() == state.reward_token_mint_id,
+        UnexpectedRewardTokenMintId
+    );
+    let mut staker = ctx.accounts.staker.load_mut()?;
+    require!(
+        ctx.accounts.user.key() == staker.staker_id,
+        StakerIdMismatch
+    );
+
+    let reward_schedule = ctx.accounts.reward_schedule.load()?;
+
+    state.update(&reward_schedule, clock.unix_timestamp);
+    staker.update(&state, clock.unix_timestamp);
+    staker.pay_rewards_forfeit_owed(&mut state);
+
+    let amount_available = ctx.accounts.reward_token_treasury.amount;
+    if !allow_partial_claim {
+        require!(
+            staker.num_rewards_claimable <= amount_available,
+            TreasuryInsufficientFunds
+        );
+    }
+
+    let amount = staker.claim_rewards(clock.unix_timestamp, amount_available);
+    state.total_rewards_claimed = state.total_rewards_claimed.saturating_add(amount);
+
+    let event = RewardsClaimed {
+        state_id: ctx.accounts.state.key(),
+        staker_id: staker.staker_id,
+        reward_amount: amount,
+        num_rewards_claimable: staker.num_rewards_claimable,
+        total_rewards_claimed: state.total_rewards_claimed,
+    };
+
+    drop(state);
+    drop(staker);
+
+    let state_id = ctx.accounts.state.key();
+    let authority_bump = ctx.accounts.authority.bump;
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.reward_token_treasury.to_account_info(),
+                to: ctx.accounts.reward_token.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+            &[&[b"authority", state_id.as_ref(), &[authority_bump]]],
+        ),
+        amount,
+    )?;
+
+    emit!(event);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(zero)]
+    pub state: AccountLoader<'info, State>,
+    #[account(zero)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(init, payer = admin, seeds = [b"authority", state.key().as_ref()], bump)]
+    pub authority: Account<'info, Authority>,
+    pub reward_token_mint: Box<Account<'info, Mint>>,
+    #[account(init, payer = admin, seeds = [b"treasury", state.key().as_ref()], bump, token::mint = reward_token_mint, token::authority = authority)]
+    pub reward_token_treasury: Box<Account<'info, TokenAccount>>,
+    #[account(init, payer = admin, seeds = [b"pool_config", state.key().as_ref()], bump, space = PoolConfig::LEN)]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AdminOnly<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut, constraint = state.load()?.assert_admin(admin.key()).is_ok() @ ErrorCode::Unauthorized)]
+    pub state: AccountLoader<'info, State>,
+}
+
+#[derive(Accounts)]
+pub struct SetZeroSupplyRewardsPolicy<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut, constraint = state.load()?.assert_admin(admin.key()).is_ok() @ ErrorCode::Unauthorized)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePoolConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(constraint = state.load()?.assert_admin(admin.key()).is_ok() @ ErrorCode::Unauthorized)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"pool_config", state.key().as_ref()], bump, constraint = pool_config.state_id == state.key())]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRewardSchedule<'info> {
+    pub admin: Signer<'info>,
+    #[account(constraint = state.load()?.assert_admin(admin.key()).is_ok() @ ErrorCode::Unauthorized)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+}
+
+#[derive(Accounts)]
+pub struct Finalize<'info> {
+    #[account(mut)]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRewards<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut, constraint = state.load()?.assert_admin(admin.key()).is_ok() @ ErrorCode::Unauthorized, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    #[account(mut, seeds = [b"treasury", state.key().as_ref()], bump = state.load()?.treasury_bump)]
+    pub reward_token_treasury: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SweepTreasury<'info> {
+    pub admin: Signer<'info>,
+    #[account(constraint = state.load()?.assert_admin(admin.key()).is_ok() @ ErrorCode::Unauthorized, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    #[account(mut, seeds = [b"treasury", state.key().as_ref()], bump = state.load()?.treasury_bump)]
+    pub reward_token_treasury: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct QueueAdminAction<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(mut, constraint = state.load()?.assert_admin(admin.key()).is_ok() @ ErrorCode::Unauthorized)]
+    pub state: AccountLoader<'info, State>,
+    #[account(init, payer = admin, space = QueuedAdminAction::LEN, seeds = [b"queued_admin_action", state.key().as_ref(), &action_id.to_le_bytes()], bump)]
+    pub queued_admin_action: Box<Account<'info, QueuedAdminAction>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminAction<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(constraint = state.load()?.assert_admin(admin.key()).is_ok() @ ErrorCode::Unauthorized)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, close = admin, constraint = queued_admin_action.state_id == state.key())]
+    pub queued_admin_action: Box<Account<'info, QueuedAdminAction>>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(mut, constraint = state.load()?.assert_admin(admin.key()).is_ok() @ ErrorCode::Unauthorized, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"pool_config", state.key().as_ref()], bump, constraint = pool_config.state_id == state.key())]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+    #[account(mut, constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(mut, close = admin, constraint = queued_admin_action.state_id == state.key())]
+    pub queued_admin_action: Box<Account<'info, QueuedAdminAction>>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    #[account(mut, seeds = [b"treasury", state.key().as_ref()], bump = state.load()?.treasury_bump)]
+    pub reward_token_treasury: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init, payer = payer, space = Multisig::LEN)]
+    pub multisig: Box<Account<'info, Multisig>>,
+    /// CHECK: PDA which signs on behalf of the multisig.
+    #[account(seeds = [b"multisig_signer", multisig.key().as_ref()], bump)]
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMultisigSigners<'info> {
+    #[account(mut)]
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(seeds = [b"multisig_signer", multisig.key().as_ref()], bump = multisig.signer_bump)]
+    pub multisig_signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(transaction_id: u64, instruction_program_id: Pubkey, instruction_accounts: Vec<MultisigTransactionAccount>, instruction_data: Vec<u8>)]
+pub struct ProposeMultisigTransaction<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(mut)]
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(init, payer = proposer, space = MultisigTransaction::len(multisig.signers.len(), instruction_accounts.len(), instruction_data.len()), seeds = [b"multisig_transaction", multisig.key().as_ref(), &transaction_id.to_le_bytes()], bump)]
+    pub multisig_transaction: Box<Account<'info, MultisigTransaction>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMultisigTransaction<'info> {
+    pub signer: Signer<'info>,
+    pub multisig: Box<Account<'info, Multisig>>,
+    #[account(mut, constraint = multisig_transaction.multisig_id == multisig.key())]
+    pub multisig_transaction: Box<Account<'info, MultisigTransaction>>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigTransaction<'info> {
+    pub multisig: Box<Account<'info, Multisig>>,
+    /// CHECK: PDA which signs on behalf of the multisig.
+    #[account(seeds = [b"multisig_signer", multisig.key().as_ref()], bump = multisig.signer_bump)]
+    pub multisig_signer: UncheckedAccount<'info>,
+    #[account(mut, constraint = multisig_transaction.multisig_id == multisig.key())]
+    pub multisig_transaction: Box<Account<'info, MultisigTransaction>>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+    #[account(mut, constraint = state.load()?.pending_admin_id == new_admin.key() @ ErrorCode::PendingAdminMismatch)]
+    pub state: AccountLoader<'info, State>,
+}
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(mut, seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    #[account(seeds = [b"pool_config", state.key().as_ref()], bump, constraint = pool_config.state_id == state.key())]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+    pub nft_mint: Box<Account<'info, Mint>>,
+    #[account(owner = mpl_token_metadata::id(), seeds = [mpl_token_metadata::state::PREFIX.as_bytes(), mpl_token_metadata::id().as_ref(), nft_mint.key().as_ref()], bump, seeds::program = mpl_token_metadata::id())]
+    pub nft_metadata: AccountInfo<'info>,
+    #[account(mut, associated_token::mint = nft_mint, associated_token::authority = user)]
+    pub nft: Box<Account<'info, TokenAccount>>,
+    #[account(init, payer = user, seeds = [b"escrow", state.key().as_ref(), nft_mint.key().as_ref()], bump, token::mint = nft_mint, token::authority = authority)]
+    pub nft_escrow: Box<Account<'info, TokenAccount>>,
+    #[account(init_if_needed, payer = user, seeds = [b"staker", state.key().as_ref(), user.key().as_ref()], bump)]
+    pub staker: AccountLoader<'info, Staker>,
+    #[account(init, payer = user, seeds = [b"locked_nft", state.key().as_ref(), nft_mint.key().as_ref()], bump)]
+    pub locked_nft: AccountLoader<'info, LockedNft>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct StakeFor<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    /// CHECK: Any wallet may be staked for, and is only used to derive its staker account.
+    pub beneficiary: UncheckedAccount<'info>,
+    #[account(mut, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    #[account(seeds = [b"pool_config", state.key().as_ref()], bump, constraint = pool_config.state_id == state.key())]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+    pub nft_mint: Box<Account<'info, Mint>>,
+    #[account(owner = mpl_token_metadata::id(), seeds = [mpl_token_metadata::state::PREFIX.as_bytes(), mpl_token_metadata::id().as_ref(), nft_mint.key().as_ref()], bump, seeds::program = mpl_token_metadata::id())]
+    pub nft_metadata: AccountInfo<'info>,
+    #[account(mut, associated_token::mint = nft_mint, associated_token::authority = depositor)]
+    pub nft: Box<Account<'info, TokenAccount>>,
+    #[account(init, payer = depositor, seeds = [b"escrow", state.key().as_ref(), nft_mint.key().as_ref()], bump, token::mint = nft_mint, token::authority = authority)]
+    pub nft_escrow: Box<Account<'info, TokenAccount>>,
+    #[account(init_if_needed, payer = depositor, seeds = [b"staker", state.key().as_ref(), beneficiary.key().as_ref()], bump)]
+    pub staker: AccountLoader<'info, Staker>,
+    #[account(init, payer = depositor, seeds = [b"locked_nft", state.key().as_ref(), nft_mint.key().as_ref()], bump)]
+    pub locked_nft: AccountLoader<'info, LockedNft>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct StakeMany<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    #[account(seeds = [b"pool_config", state.key().as_ref()], bump, constraint = pool_config.state_id == state.key())]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+    #[account(init_if_needed, payer = user, seeds = [b"staker", state.key().as_ref(), user.key().as_ref()], bump)]
+    pub staker: AccountLoader<'info, Staker>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    #[account(mut, seeds = [b"staker", state.key().as_ref(), user.key().as_ref()], bump = staker.load()?.bump)]
+    pub staker: AccountLoader<'info, Staker>,
+    pub reward_token_mint: Box<Account<'info, Mint>>,
+    #[account(init_if_needed, payer = user, associated_token::mint = reward_token_mint, associated_token::authority = user)]
+    pub reward_token: Box<Account<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"treasury", state.key().as_ref()], bump = state.load()?.treasury_bump)]
+    pub reward_token_treasury: Box<Account<'info, TokenAccount>>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundTreasury<'info> {
+    pub funder: Signer<'info>,
+    #[account(mut)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut)]
+    pub funder_reward_token: Box<Account<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"treasury", state.key().as_ref()], bump = state.load()?.treasury_bump)]
+    pub reward_token_treasury: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    pub nft_mint: Box<Account<'info, Mint>>,
+    #[account(mut, associated_token::mint = nft_mint, associated_token::authority = user)]
+    pub nft: Box<Account<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"escrow", state.key().as_ref(), nft_mint.key().as_ref()], bump = locked_nft.load()?.escrow_bump)]
+    pub nft_escrow: Box<Account<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"staker", state.key().as_ref(), user.key().as_ref()], bump = staker.load()?.bump)]
+    pub staker: AccountLoader<'info, Staker>,
+    #[account(mut, close = user, seeds = [b"locked_nft", state.key().as_ref(), nft_mint.key().as_ref()], bump = locked_nft.load()?.bump)]
+    pub locked_nft: AccountLoader<'info, LockedNft>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnstakeMany<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    #[account(mut, seeds = [b"staker", state.key().as_ref(), user.key().as_ref()], bump = staker.load()?.bump)]
+    pub staker: AccountLoader<'info, Staker>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ReturnNft<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Must be the staker recorded in `locked_nft`, which is checked in the instruction.
+    #[account(mut)]
+    pub staker_wallet: UncheckedAccount<'info>,
+    #[account(mut, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    pub nft_mint: Box<Account<'info, Mint>>,
+    #[account(init_if_needed, payer = payer, associated_token::mint = nft_mint, associated_token::authority = staker_wallet)]
+    pub nft: Box<Account<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"escrow", state.key().as_ref(), nft_mint.key().as_ref()], bump = locked_nft.load()?.escrow_bump)]
+    pub nft_escrow: Box<Account<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"staker", state.key().as_ref(), staker_wallet.key().as_ref()], bump = staker.load()?.bump)]
+    pub staker: AccountLoader<'info, Staker>,
+    #[account(mut, close = staker_wallet, seeds = [b"locked_nft", state.key().as_ref(), nft_mint.key().as_ref()], bump = locked_nft.load()?.bump)]
+    pub locked_nft: AccountLoader<'info, LockedNft>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Extend<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    pub nft_mint: Box<Account<'info, Mint>>,
+    #[account(mut, seeds = [b"staker", state.key().as_ref(), user.key().as_ref()], bump = staker.load()?.bump)]
+    pub staker: AccountLoader<'info, Staker>,
+    #[account(mut, seeds = [b"locked_nft", state.key().as_ref(), nft_mint.key().as_ref()], bump = locked_nft.load()?.bump)]
+    pub locked_nft: AccountLoader<'info, LockedNft>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendAllToMax<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, constraint = authority.state_id == state.key())]
+    pub state: AccountLoader<'info, State>,
+    #[account(constraint = reward_schedule.key() == state.load()?.reward_schedule_id @ ErrorCode::UnexpectedRewardSchedule)]
+    pub reward_schedule: AccountLoader<'info, RewardSchedule>,
+    #[account(seeds = [b"authority", state.key().as_ref()], bump = authority.bump)]
+    pub authority: Account<'info, Authority>,
+    #[account(mut, seeds = [b"staker", state.key().as_ref(), user.key().as_ref()], bump = staker.load()?.bump)]
+    pub staker: AccountLoader<'info, Staker>,
+}
+
+/// Outcome of extending the lock of a single NFT through `extend_all_to_max`. Both are zero
+/// should the NFT's lock not have been extended.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RelockResult {
+    pub num_days_extended: u64,
+    pub venft_amount_minted: u64,
+}
+
+#[event]
+pub struct PoolInitialized {
+    pub state_id: Pubkey,
+    pub admin_id: Pubkey,
+    pub reward_token_mint_id: Pubkey,
+    pub reward_schedule_id: Pubkey,
+    pub min_lock_duration_in_days: u64,
+    pub max_lock_duration_in_days: u64,
+    pub deployed_at: i64,
+}
+
+#[event]
+pub struct NftStaked {
+    pub state_id: Pubkey,
+    pub staker_id: Pubkey,
+    pub mint_id: Pubkey,
+    pub lock_duration_in_days: u64,
+    pub venft_amount_minted: u64,
+    pub unlocks_at: i64,
+    pub staker_num_locked_nfts: u64,
+    pub total_num_locked_nfts: u64,
+}
+
+#[event]
+pub struct LockExtended {
+    pub state_id: Pubkey,
+    pub staker_id: Pubkey,
+    pub mint_id: Pubkey,
+    pub num_days_extended: u64,
+    /// Number of days the NFT remains locked for starting from the extension.
+    pub lock_duration_in_days: u64,
+    pub venft_amount_minted: u64,
+    pub unlocks_at: i64,
+    pub staker_num_locked_nfts: u64,
+    pub total_num_locked_nfts: u64,
+}
+
+#[event]
+pub struct PerpetualLockSet {
+    pub state_id: Pubkey,
+    pub staker_id: Pubkey,
+    pub mint_id: Pubkey,
+    pub perpetual: bool,
+    /// veNFT's held every day while perpetually locked, or minted once no longer perpetually
+    /// locked.
+    pub venft_amount: u64,
+    pub unlocks_at: i64,
+}
+
+#[event]
+pub struct NftUnstaked {
+    pub state_id: Pubkey,
+    pub staker_id: Pubkey,
+    pub mint_id: Pubkey,
+    /// Amount of reward token subunits forfeited by the staker for unstaking early.
+    pub num_rewards_forfeited: u64,
+    pub staker_num_locked_nfts: u64,
+    pub total_num_locked_nfts: u64,
+}
+
+/// Emitted by `unstake_many` for every NFT supplied which may not be unlocked yet.
+#[event]
+pub struct NftUnstakeSkipped {
+    pub state_id: Pubkey,
+    pub staker_id: Pubkey,
+    pub mint_id: Pubkey,
+    pub unlocks_at: i64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub state_id: Pubkey,
+    pub staker_id: Pubkey,
+    /// Amount of reward token subunits paid out to the staker.
+    pub reward_amount: u64,
+    /// Amount of reward token subunits which remain claimable by the staker.
+    pub num_rewards_claimable: u64,
+    pub total_rewards_claimed: u64,
+}
+
+pub fn assert_lockable_nft(
+    mint_id: Pubkey,
+    metadata_account: &AccountInfo,
+    pool_config: &PoolConfig,
+) -> Result<()> {
+    let metadata = mpl_token_metadata::state::Metadata::from_account_info(metadata_account)?;
+
+    require!(metadata.mint == mint_id, MetadataMintMismatch,);
+
+    pool_config.assert_lockable(
+        metadata.data.creators.as_deref(),
+        metadata.collection.as_ref(),
+    )
+}
+
+/// Bumps of the escrow and locked NFT accounts to be created for a NFT supplied to `stake_many`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StakeManyBumps {
+    pub escrow_bump: u8,
+    pub locked_nft_bump: u8,
+}
+
+/// Check that the addresses of the remaining accounts supplied to `stake_many` form groups of
+/// `STAKE_MANY_ACCOUNTS_PER_NFT` accounts for distinct NFT's, each holding the NFT's mint, its
+/// metadata, the staker's associated token account holding it, and its escrow and locked NFT
+/// accounts. Returns the bumps of the escrow and locked NFT accounts of each group.
+pub fn validate_stake_many_accounts(
+    program_id: &Pubkey,
+    state_id: Pubkey,
+    user_id: Pubkey,
+    account_ids: &[Pubkey],
+) -> Result<Vec<StakeManyBumps>> {
+    require!(
+        !account_ids.is_empty() && account_ids.len() % STAKE_MANY_ACCOUNTS_PER_NFT == 0,
+        InvalidRemainingAccounts
+    );
+
+    let mut mint_ids: Vec<Pubkey> = Vec::with_capacity(account_ids.len());
+    let mut bumps = Vec::with_capacity(account_ids.len() / STAKE_MANY_ACCOUNTS_PER_NFT);
+
+    for group in account_ids.chunks(STAKE_MANY_ACCOUNTS_PER_NFT) {
+        let (mint_id, metadata_id, nft_id, escrow_id, locked_nft_id) = match *group {
+            [mint_id, metadata_id, nft_id, escrow_id, locked_nft_id] => {
+                (mint_id, metadata_id, nft_id, escrow_id, locked_nft_id)
+            }
+            _ => return err!(InvalidRemainingAccounts),
+        };
+
+        require!(!mint_ids.contains(&mint_id), InvalidRemainingAccounts);
+        mint_ids.push(mint_id);
+
+        let metadata_program_id = mpl_token_metadata::id();
+        let (expected_metadata_id, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                metadata_program_id.as_ref(),
+                mint_id.as_ref(),
+            ],
+            &metadata_program_id,
+        );
+        let (expected_escrow_id, escrow_bump) = Pubkey::find_program_address(
+            &[b"escrow", state_id.as_ref(), mint_id.as_ref()],
+            program_id,
+        );
+        let (expected_locked_nft_id, locked_nft_bump) = Pubkey::find_program_address(
+            &[b"locked_nft", state_id.as_ref(), mint_id.as_ref()],
+            program_id,
+        );
+
+        require!(
+            metadata_id == expected_metadata_id
+                && nft_id
+                    == anchor_spl::associated_token::get_associated_token_address(
+                        &user_id, &mint_id
+                    )
+                && escrow_id == expected_escrow_id
+                && locked_nft_id == expected_locked_nft_id,
+            InvalidRemainingAccounts
+        );
+
+        bumps.push(StakeManyBumps {
+            escrow_bump,
+            locked_nft_bump,
+        });
+    }
+
+    Ok(bumps)
+}
+
+#[account]
+#[derive(Default)]
+pub struct Authority {
+    pub state_id: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq)]
+pub struct AllowedCreator {
+    pub address: Pubkey,
+    pub must_be_verified: bool,
+}
+
+/// Which parts of a NFT's metadata are checked against the pool's allowlists before it may be
+/// staked.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq)]
+pub enum NftGatingMode {
+    #[default]
+    Creator,
+    Collection,
+    CreatorAndCollection,
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct PoolConfig {
+    pub state_id: Pubkey,
+
+    pub nft_gating_mode: NftGatingMode,
+
+    /// NFTs may only be staked if one of their creators is in this list.
+    pub allowed_creators: Vec<AllowedCreator>,
+    /// NFTs may only be staked if they belong to a verified collection whose mint is in this
+    /// list.
+    pub allowed_collections: Vec<Pubkey>,
+}
+
+impl PoolConfig {
+    pub const LEN: usize = 8
+        + 32
+        + 1
+        + (4 + MAX_NUM_ALLOWED_CREATORS * (32 + 1))
+        + (4 + MAX_NUM_ALLOWED_COLLECTIONS * 32);
+
+    pub fn assert_lockable(
+        &self,
+        creators: Option<&[mpl_token_metadata::state::Creator]>,
+        collection: Option<&mpl_token_metadata::state::Collection>,
+    ) -> Result<()> {
+        let assert_allowed_creators = || match creators {
+            Some(creators) => self.assert_allowed_creators(creators),
+            None => Err(ErrorCode::MetadataHasNoCreators.into()),
+        };
+
+        match self.nft_gating_mode {
+            NftGatingMode::Creator => assert_allowed_creators(),
+            NftGatingMode::Collection => self.assert_allowed_collection(collection),
+            NftGatingMode::CreatorAndCollection => {
+                assert_allowed_creators()?;
+                self.assert_allowed_collection(collection)
+            }
+        }
+    }
+
+    pub fn add_allowed_creator(&mut self, address: Pubkey, must_be_verified: bool) -> Result<()> {
+        if let Some(allowed_creator) = self
+            .allowed_creators
+            .iter_mut()
+            .find(|allowed_creator| allowed_creator.address == address)
+        {
+            allowed_creator.must_be_verified = must_be_verified;
+            return Ok(());
+        }
+
+        require!(
+            self.allowed_creators.len() < MAX_NUM_ALLOWED_CREATORS,
+            AllowedCreatorsFull
+        );
+
+        self.allowed_creators.push(AllowedCreator {
+            address,
+            must_be_verified,
+        });
+
+        Ok(())
+    }
+
+    pub fn remove_allowed_creator(&mut self, address: Pubkey) -> Result<()> {
+        let index = self
+            .allowed_creators
+            .iter()
+            .position(|allowed_creator| allowed_creator.address == address)
+            .ok_or(ErrorCode::AllowedCreatorNotFound)?;
+
+        self.allowed_creators.remove(index);
+
+        Ok(())
+    }
+
+    /// Assert that at least one of the creators specified in a NFT's metadata is allowed by the
+    /// pool, and is verified should the pool require it to be.
+    pub fn assert_allowed_creators(
+        &self,
+        creators: &[mpl_token_metadata::state::Creator],
+    ) -> Result<()> {
+        require!(!creators.is_empty(), MetadataHasNoCreators);
+
+        let mut found_unverified_creator = false;
+
+        for creator in creators {
+            let allowed_creator = match self
+                .allowed_creators
+                .iter()
+                .find(|allowed_creator| allowed_creator.address == creator.address)
+            {
+                Some(allowed_creator) => allowed_creator,
+                None => continue,
+            };
+
+            if allowed_creator.must_be_verified && !creator.verified {
+                found_unverified_creator = true;
+                continue;
+            }
+
+            return Ok(());
+        }
+
+        if found_unverified_creator {
+            return Err(ErrorCode::MetadataCreatorUnverified.into());
+        }
+
+        Err(ErrorCode::UnexpectedMetadataCreator.into())
+    }
+
+    pub fn add_allowed_collection(&mut self, collection_mint_id: Pubkey) -> Result<()> {
+        if self.allowed_collections.contains(&collection_mint_id) {
+            return Ok(());
+        }
+
+        require!(
+            self.allowed_collections.len() < MAX_NUM_ALLOWED_COLLECTIONS,
+            AllowedCollectionsFull
+        );
+
+        self.allowed_collections.push(collection_mint_id);
+
+        Ok(())
+    }
+
+    pub fn remove_allowed_collection(&mut self, collection_mint_id: Pubkey) -> Result<()> {
+        let index = self
+            .allowed_collections
+            .iter()
+            .position(|allowed_collection| *allowed_collection == collection_mint_id)
+            .ok_or(ErrorCode::AllowedCollectionNotFound)?;
+
+        self.allowed_collections.remove(index);
+
+        Ok(())
+    }
+
+    /// Assert that a NFT's metadata specifies a verified collection which is allowed by the pool.
+    pub fn assert_allowed_collection(
+        &self,
+        collection: Option<&mpl_token_metadata::state::Collection>,
+    ) -> Result<()> {
+        match collection {
+            Some(collection) => require!(
+                collection.verified && self.allowed_collections.contains(&collection.key),
+                UnexpectedMetadataCollection
+            ),
+            None => return Err(ErrorCode::UnexpectedMetadataCollection.into()),
+        }
+
+        Ok(())
+    }
+}
+
+#[account(zero_copy)]
+#[derive(Default, Debug)]
+pub struct LockedNft {
+    pub staker_id: Pubkey,
+    pub mint_id: Pubkey,
+
+    pub locked_at: i64,
+    pub lock_duration_in_days: u64,
+
+    /// veNFT's minted in exchange for this NFT, described as the amount held on the day they were
+    /// minted, counted from the day the program was deployed, and the amount held on the day
+    /// after, from which they decay by `VENFTS_PER_DAY` every day up until `venft_end_day`. They
+    /// are burned from the staker's balance and the total veNFT supply once the NFT is unstaked.
+    pub venft_start_day: u64,
+    pub venft_start_balance: u64,
+    pub venft_decaying_balance: u64,
+    pub venft_end_day: u64,
+    /// Nonzero while the NFT is perpetually locked, in which case it holds the constant amount of
+    /// veNFT's held in exchange for the NFT every day on top of its decaying veNFT's, fixed when
+    /// the NFT was perpetually locked.
+    pub perpetual_venft_balance: u64,
+
+    /// Amount of reward token subunits accrued for the veNFT's held in exchange for this NFT on
+    /// every day before `rewards_settled_day`, which early unstake penalties are based on.
+    pub num_rewards_accrued: u64,
+    pub rewards_settled_day: u64,
+
+    /// Bumps of this account and of the escrow holding the locked NFT.
+    pub bump: u8,
+    pub escrow_bump: u8,
+    pub padding: [u8; 6],
+}
+
+impl LockedNft {
+    /// Decaying veNFT's held in exchange for this NFT on a day, counted from the day the program
+    /// was deployed.
+    pub fn venft_balance(&self, day: u64) -> u64 {
+        if day < self.venft_start_day || day >= self.venft_end_day {
+            return 0;
+        }
+        if day == self.venft_start_day {
+            return self.venft_start_balance;
+        }
+        self.venft_decaying_balance
+            .saturating_sub((day - self.venft_start_day - 1).saturating_mul(VENFTS_PER_DAY))
+    }
+
+    pub fn is_perpetual(&self) -> bool {
+        self.perpetual_venft_balance != 0
+    }
+
+    /// Accrue rewards for the veNFT's held in exchange for this NFT on every day since rewards
+    /// were last settled up until `current_day`. Must be called before the NFT's veNFT's change.
+    pub fn settle_rewards(&mut self, state: &State, current_day: u64) {
+        let start_day = self.rewards_settled_day;
+        if current_day <= start_day {
+            return;
+        }
+        self.rewards_settled_day = current_day;
+
+        let mut num_rewards_scaled = (self.perpetual_venft_balance as u128)
+            .wrapping_mul(state.reward_per_venft(start_day as usize, current_day as usize));
+
+        if (start_day..current_day).contains(&self.venft_start_day)
+            && self.venft_start_day < self.venft_end_day
+        {
+            let day = self.venft_start_day as usize;
+            num_rewards_scaled = num_rewards_scaled.wrapping_add(
+                (self.venft_start_balance as u128)
+                    .wrapping_mul(state.reward_per_venft(day, day + 1)),
+            );
+        }
+
+        // Decaying veNFT's are settled over the days after they were minted at once, the same
+        // way stakers settle them in between breakpoints.
+
+        let first_day = start_day.max(self.venft_start_day.saturating_add(1));
+        let last_day = current_day.min(self.venft_end_day);
+        if first_day < last_day {
+            let reward_per_venft = state.reward_per_venft(first_day as usize, last_day as usize);
+            let day_weighted_reward_per_venft =
+                state.day_weighted_reward_per_venft(first_day as usize, last_day as usize);
+            num_rewards_scaled = num_rewards_scaled
+                .wrapping_add(
+                    (self.venft_balance(first_day) as u128).wrapping_mul(reward_per_venft),
+                )
+                .wrapping_sub(
+                    (VENFTS_PER_DAY as u128).wrapping_mul(
+                        day_weighted_reward_per_venft
+                            .wrapping_sub((first_day as u128).wrapping_mul(reward_per_venft)),
+                    ),
+                );
+        }
+
+        self.num_rewards_accrued = self
+            .num_rewards_accrued
+            .saturating_add((num_rewards_scaled / REWARD_INDEX_PRECISION) as u64);
+    }
+
+    /// Perpetually locked NFTs never unlock, and may not be extended any further.
+    pub fn num_days_left_before_unlocked(&self, current_time: i64) -> u64 {
+        if self.is_perpetual() {
+            return u64::MAX;
+        }
+
+        let days_elapsed_since_locked = days_between_timestamps(self.locked_at, current_time);
+        self.lock_duration_in_days
+            .saturating_sub(days_elapsed_since_locked)
+    }
+
+    pub fn max_num_days_may_be_extended(
+        &self,
+        current_time: i64,
+        max_lock_duration_in_days: u64,
+    ) -> u64 {
+        let days_left_before_unlocked = self.num_days_left_before_unlocked(current_time);
+        max_lock_duration_in_days.saturating_sub(days_left_before_unlocked)
+    }
+
+    pub fn extend_lock_duration(&mut self, current_time: i64, lock_duration_in_days: u64) {
+        let days_left_before_unlocked = self.num_days_left_before_unlocked(current_time);
+        self.locked_at = current_time;
+        self.lock_duration_in_days =
+            days_left_before_unlocked.saturating_add(lock_duration_in_days);
+    }
+
+    pub fn unlocks_at(&self) -> i64 {
+        if self.is_perpetual() {
+            return i64::MAX;
+        }
+
+        self.locked_at.saturating_add(
+            self.lock_duration_in_days
+                .saturating_mul(24 * 60 * 60)
+                .try_into()
+                .unwrap_or(i64::MAX),
+        )
+    }
+
+    pub fn may_be_unlocked(&self, user_id: Pubkey, mint_id: Pubkey, current_time: i64) -> bool {
+        self.staker_id == user_id && self.mint_id == mint_id && current_time > self.unlocks_at()
+    }
+}
+
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct State {
+    pub admin_id: Pubkey,
+    /// Admin proposed through `propose_admin` which has yet to call `accept_admin`.
+    pub pending_admin_id: Pubkey,
+
+    pub reward_token_mint_id: Pubkey,
+
+    pub deployed_at: i64,
+    pub last_updated_at: i64,
+
+    pub total_num_locked_nfts: u64,
+
+    pub reward_schedule_id: Pubkey,
+
+    pub treasury_bump: u8,
+    pub padding: [u8; 7],
+
+    /// Total amount of reward token subunits deposited through `fund_treasury`, and paid out to
+    /// stakers.
+    pub total_rewards_funded: u64,
+    pub total_rewards_claimed: u64,
+
+    pub min_lock_duration_in_days: u64,
+    pub max_lock_duration_in_days: u64,
+
+    /// A combination of `PAUSE_*` flags denoting which instructions are paused.
+    pub paused_instructions: u64,
+
+    /// Zero unless the pool has been sunset, after which escrowed NFTs may be returned to their
+    /// stakers through `return_nft`.
+    pub sunset_at: i64,
+
+    /// Number of seconds admin actions are timelocked for once queued. Zero if admin actions may
+    /// be applied directly.
+    pub admin_action_delay_in_seconds: i64,
+    pub num_queued_admin_actions: u64,
+
+    /// Zero until the pool is finalized, after which `total_rewards_emitted` holds the amount of
+    /// reward token subunits emitted to stakers over the pool's lifetime.
+    pub finalized_at: i64,
+    pub total_rewards_emitted: u64,
+
+    /// One of the `ZERO_SUPPLY_REWARDS_*` policies.
+    pub zero_supply_rewards_policy: u64,
+    /// Amount of reward token subunits emitted on days on which no veNFT's were in circulation,
+    /// under any policy.
+    pub total_rewards_carried_over: u64,
+    /// Amount of reward token subunits carried forward which have yet to reach a day on which
+    /// veNFT's are in circulation.
+    pub rewards_pending_carry_over: u64,
+    /// Amount of reward token subunits set aside to be reclaimed by the admin, and reclaimed so
+    /// far.
+    pub total_rewards_reclaimable: u64,
+    pub total_rewards_reclaimed: u64,
+
+    /// Percentage of their accrued rewards, in basis points, which stakers forfeit when unstaking
+    /// a NFT which remains locked for the pool's maximum lock duration. The penalty scales down
+    /// linearly with the number of days the NFT remains locked for.
+    pub early_unstake_penalty_bps: u64,
+    /// One of the `EARLY_UNSTAKE_PENALTY_TO_*` destinations.
+    pub early_unstake_penalty_destination: u64,
+    /// Amount of reward token subunits forfeited by stakers unstaking early.
+    pub total_rewards_forfeited: u64,
+
+    /// Index 0 starts on the first day after the program is deployed.
+    pub venft_supply: [u64; 1461],
+    /// Amount of reward token subunits carried forward onto each day on top of the reward
+    /// schedule.
+    pub rewards_carried_over: [u64; 1461],
+    /// veNFT's held every day in exchange for perpetually locked NFTs, on top of `venft_supply`.
+    pub perpetual_venft_supply: u64,
+    /// Cumulative amount of reward token subunits emitted per veNFT before each day, scaled by
+    /// `REWARD_INDEX_PRECISION`. Index 0 is zero, and each day is checkpointed once it has
+    /// elapsed.
+    pub reward_per_venft_index: [u128; 1462],
+    /// Cumulative sum of the amount of reward token subunits emitted per veNFT on each day before
+    /// each day, weighted by the day, such that rewards for veNFT balances which change linearly
+    /// over a span of days may be settled at once. Wraps around on overflow.
+    pub day_weighted_reward_per_venft_index: [u128; 1462],
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            admin_id: Default::default(),
+            pending_admin_id: Default::default(),
+
+            reward_token_mint_id: Default::default(),
+
+            deployed_at: Default::default(),
+            last_updated_at: Default::default(),
+
+            total_num_locked_nfts: Default::default(),
+
+            reward_schedule_id: Default::default(),
+
+            treasury_bump: Default::default(),
+            padding: Default::default(),
+
+            total_rewards_funded: Default::default(),
+            total_rewards_claimed: Default::default(),
+
+            min_lock_duration_in_days: MIN_LOCK_DURATION_DAYS,
+            max_lock_duration_in_days: MAX_LOCK_DURATION_DAYS,
+
+            paused_instructions: Default::default(),
+
+            sunset_at: Default::default(),
+
+            admin_action_delay_in_seconds: Default::default(),
+            num_queued_admin_actions: Default::default(),
+
+            finalized_at: Default::default(),
+            total_rewards_emitted: Default::default(),
+
+            zero_supply_rewards_policy: ZERO_SUPPLY_REWARDS_RECLAIMABLE,
+            total_rewards_carried_over: Default::default(),
+            rewards_pending_carry_over: Default::default(),
+            total_rewards_reclaimable: Default::default(),
+            total_rewards_reclaimed: Default::default(),
+
+            early_unstake_penalty_bps: Default::default(),
+            early_unstake_penalty_destination: EARLY_UNSTAKE_PENALTY_TO_TREASURY,
+            total_rewards_forfeited: Default::default(),
+
+            venft_supply: [0u64; MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize],
+            rewards_carried_over: [0u64; MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize],
+            perpetual_venft_supply: Default::default(),
+            reward_per_venft_index: [0u128; MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize + 1],
+            day_weighted_reward_per_venft_index: [0u128;
+                MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize + 1],
+        }
+    }
+}
+
+impl State {
+    /// Apply the pool's zero supply rewards policy to, and checkpoint the reward per veNFT index
+    /// of, every day which has elapsed since the pool was last updated. The veNFT supply of a day
+    /// is final once the day has elapsed.
+    pub fn update(&mut self, reward_schedule: &RewardSchedule, current_time: i64) {
+        let first_day: usize = days_between_timestamps(self.deployed_at, self.last_updated_at)
+            .min(MAX_DURATION_TO_EMIT_REWARDS_DAYS) as usize;
+        let last_day: usize = days_between_timestamps(self.deployed_at, current_time)
+            .min(MAX_DURATION_TO_EMIT_REWARDS_DAYS) as usize;
+
+        // Perpetual veNFT's only change right after the pool is updated, so the amount currently
+        // held applies to every day which has yet to be checkpointed.
+
+        for day in first_day..last_day {
+            self.reward_per_venft_index[day + 1] = self.reward_per_venft_index[day];
+            self.day_weighted_reward_per_venft_index[day + 1] =
+                self.day_weighted_reward_per_venft_index[day];
+
+            let total_venft_supply =
+                self.venft_supply[day].saturating_add(self.perpetual_venft_supply);
+            if total_venft_supply != 0 {
+                self.rewards_carried_over[day] =
+                    std::mem::take(&mut self.rewards_pending_carry_over);
+                let reward_per_venft = self.reward_for_day(reward_schedule, day) as u128
+                    * REWARD_INDEX_PRECISION
+                    / total_venft_supply as u128;
+                self.reward_per_venft_index[day + 1] += reward_per_venft;
+                self.day_weighted_reward_per_venft_index[day + 1] = self
+                    .day_weighted_reward_per_venft_index[day + 1]
+                    .wrapping_add(reward_per_venft.wrapping_mul(day as u128));
+                continue;
+            }
+
+            let reward_emitted = reward_schedule
+                .reward_for_day(day)
+                .saturating_mul(SUBUNITS_PER_REWARD_TOKEN);
+            self.total_rewards_carried_over = self
+                .total_rewards_carried_over
+                .saturating_add(reward_emitted);
+
+            match self.zero_supply_rewards_policy {
+                ZERO_SUPPLY_REWARDS_CARRY_FORWARD => {
+                    self.rewards_pending_carry_over = self
+                        .rewards_pending_carry_over
+                        .saturating_add(reward_emitted);
+                }
+                _ => {
+                    self.total_rewards_reclaimable = self
+                        .total_rewards_reclaimable
+                        .saturating_add(reward_emitted);
+                }
+            }
+        }
+
+        self.last_updated_at = self.last_updated_at.max(current_time);
+    }
+
+    /// Amount of reward token subunits emitted per veNFT held from `start_day` up until
+    /// `end_day`, scaled by `REWARD_INDEX_PRECISION`. Only days which have elapsed as of the last
+    /// time the pool was updated are counted.
+    pub fn reward_per_venft(&self, start_day: usize, end_day: usize) -> u128 {
+        let index = |day: usize| {
+            self.reward_per_venft_index
+                .get(day)
+                .or_else(|| self.reward_per_venft_index.last())
+                .copied()
+                .unwrap_or(0)
+        };
+        index(end_day).saturating_sub(index(start_day))
+    }
+
+    /// Sum of the amount of reward token subunits emitted per veNFT on each day from `start_day`
+    /// up until `end_day`, weighted by the day, scaled by `REWARD_INDEX_PRECISION`. The sum wraps
+    /// around on overflow.
+    pub fn day_weighted_reward_per_venft(&self, start_day: usize, end_day: usize) -> u128 {
+        let index = |day: usize| {
+            self.day_weighted_reward_per_venft_index
+                .get(day)
+                .or_else(|| self.day_weighted_reward_per_venft_index.last())
+                .copied()
+                .unwrap_or(0)
+        };
+        index(end_day.max(start_day)).wrapping_sub(index(start_day))
+    }
+
+    /// Amount of reward token subunits emitted on a day, including rewards carried forward onto
+    /// it.
+    pub fn reward_for_day(&self, reward_schedule: &RewardSchedule, day: usize) -> u64 {
+        reward_schedule
+            .reward_for_day(day)
+            .saturating_mul(SUBUNITS_PER_REWARD_TOKEN)
+            .saturating_add(self.rewards_carried_over.get(day).copied().unwrap_or(0))
+    }
+
+    /// Once the reward schedule is sealed, the pool is updated first so that days which have
+    /// already elapsed are settled under the policy in place while they elapsed. Rewards already
+    /// being carried forward keep being carried forward to stakers when switching to the
+    /// `ZERO_SUPPLY_REWARDS_RECLAIMABLE` policy.
+    pub fn set_zero_supply_rewards_policy(
+        &mut self,
+        reward_schedule: &RewardSchedule,
+        current_time: i64,
+        zero_supply_rewards_policy: u64,
+    ) -> Result<()> {
+        require!(
+            zero_supply_rewards_policy == ZERO_SUPPLY_REWARDS_CARRY_FORWARD
+                || zero_supply_rewards_policy == ZERO_SUPPLY_REWARDS_RECLAIMABLE,
+            UnknownZeroSupplyRewardsPolicy
+        );
+
+        if reward_schedule.is_sealed() {
+            self.update(reward_schedule, current_time);
+        }
+
+        self.zero_supply_rewards_policy = zero_supply_rewards_policy;
+
+        Ok(())
+    }
+
+    /// Number of days a locked NFT's lock may be extended by such that it remains locked for the
+    /// pool's maximum lock duration, without extending past the end of reward emissions. Zero
+    /// should the lock not be extendable by at least the pool's minimum lock duration.
+    pub fn max_num_days_may_be_extended(&self, locked_nft: &LockedNft, current_time: i64) -> u64 {
+        let days_left_before_reward_emissions_end = MAX_DURATION_TO_EMIT_REWARDS_DAYS
+            .saturating_sub(days_between_timestamps(self.deployed_at, current_time));
+
+        let num_days = locked_nft.max_num_days_may_be_extended(
+            current_time,
+            self.max_lock_duration_in_days
+                .min(days_left_before_reward_emissions_end),
+        );
+
+        match num_days >= self.min_lock_duration_in_days.max(1) {
+            true => num_days,
+            false => 0,
+        }
+    }
+
+    /// Assert that NFT's may be locked for `lock_duration_in_days` days starting from
+    /// `current_time`, which is bounded by the pool's lock bounds and by the end of reward
+    /// emissions.
+    pub fn assert_lock_duration_allowed(
+        &self,
+        current_time: i64,
+        lock_duration_in_days: u64,
+    ) -> Result<()> {
+        let days_left_before_reward_emissions_end: u64 = MAX_DURATION_TO_EMIT_REWARDS_DAYS
+            .saturating_sub(days_between_timestamps(self.deployed_at, current_time));
+
+        let min_lock_duration_allowed = self.min_lock_duration_in_days;
+        let max_lock_duration_allowed = self
+            .max_lock_duration_in_days
+            .min(days_left_before_reward_emissions_end);
+
+        require!(
+            (min_lock_duration_allowed..=max_lock_duration_allowed)
+                .contains(&lock_duration_in_days),
+            MaxPossibleLockDurationExceeded
+        );
+
+        Ok(())
+    }
+
+    pub fn set_early_unstake_penalty(
+        &mut self,
+        early_unstake_penalty_bps: u64,
+        early_unstake_penalty_destination: u64,
+    ) -> Result<()> {
+        require!(
+            early_unstake_penalty_bps <= 10_000
+                && (early_unstake_penalty_destination == EARLY_UNSTAKE_PENALTY_TO_TREASURY
+                    || early_unstake_penalty_destination == EARLY_UNSTAKE_PENALTY_TO_STAKERS),
+            InvalidEarlyUnstakePenalty
+        );
+
+        self.early_unstake_penalty_bps = early_unstake_penalty_bps;
+        self.early_unstake_penalty_destination = early_unstake_penalty_destination;
+
+        Ok(())
+    }
+
+    /// Amount of reward token subunits out of `num_rewards_accrued` forfeited for unstaking a NFT
+    /// which remains locked for `num_days_left_before_unlocked` days.
+    pub fn early_unstake_penalty(
+        &self,
+        num_rewards_accrued: u64,
+        num_days_left_before_unlocked: u64,
+    ) -> u64 {
+        let num_days_left_before_unlocked =
+            num_days_left_before_unlocked.min(self.max_lock_duration_in_days);
+
+        (num_rewards_accrued as u128
+            * self.early_unstake_penalty_bps as u128
+            * num_days_left_before_unlocked as u128
+            / (10_000 * self.max_lock_duration_in_days.max(1) as u128)) as u64
+    }
+
+    /// Forfeited rewards are either set aside to be reclaimed by the admin, or carried forward
+    /// onto the next day on which veNFT's are in circulation.
+    pub fn forfeit_rewards(&mut self, num_rewards_forfeited: u64) {
+        self.total_rewards_forfeited = self
+            .total_rewards_forfeited
+            .saturating_add(num_rewards_forfeited);
+
+        match self.early_unstake_penalty_destination {
+            EARLY_UNSTAKE_PENALTY_TO_STAKERS => {
+                self.rewards_pending_carry_over = self
+                    .rewards_pending_carry_over
+                    .saturating_add(num_rewards_forfeited);
+            }
+            _ => {
+                self.total_rewards_reclaimable = self
+                    .total_rewards_reclaimable
+                    .saturating_add(num_rewards_forfeited);
+            }
+        }
+    }
+
+    /// Mark every reward set aside to be reclaimed as reclaimed, and return the amount of reward
+    /// token subunits newly reclaimed. Once the pool is finalized, they are swept along with the
+    /// rest of the treasury's surplus instead.
+    pub fn reclaim_rewards(&mut self) -> Result<u64> {
+        require!(!self.is_finalized(), AlreadyFinalized);
+
+        let amount_reclaimed = self
+            .total_rewards_reclaimable
+            .saturating_sub(self.total_rewards_reclaimed);
+        self.total_rewards_reclaimed = self.total_rewards_reclaimable;
+
+        Ok(amount_reclaimed)
+    }
+
+    /// Assert that `admin_id` is the pool's admin, which every admin-only instruction requires of
+    /// its signer.
+    pub fn assert_admin(&self, admin_id: Pubkey) -> Result<()> {
+        require!(self.admin_id == admin_id, Unauthorized);
+
+        Ok(())
+    }
+
+    pub fn propose_admin(&mut self, new_admin_id: Pubkey) {
+        self.pending_admin_id = new_admin_id;
+    }
+
+    /// Hand the pool over to the admin proposed through `propose_admin`, provided that they are
+    /// the one accepting it.
+    pub fn accept_admin(&mut self, new_admin_id: Pubkey) -> Result<()> {
+        require!(
+            self.pending_admin_id != Pubkey::default() && self.pending_admin_id == new_admin_id,
+            PendingAdminMismatch
+        );
+
+        self.admin_id = std::mem::take(&mut self.pending_admin_id);
+
+        Ok(())
+    }
+
+    pub fn set_lock_duration_bounds(
+        &mut self,
+        min_lock_duration_in_days: u64,
+        max_lock_duration_in_days: u64,
+    ) -> Result<()> {
+        require!(
+            min_lock_duration_in_days >= 1
+                && min_lock_duration_in_days <= max_lock_duration_in_days,
+            InvalidLockDurationBounds
+        );
+        require!(
+            max_lock_duration_in_days <= MAX_LOCK_DURATION_DAYS,
+            MaxPossibleLockDurationExceeded
+        );
+
+        // NFTs locked for longer than a lowered maximum lock duration keep the veNFT's already
+        // minted for them, and may not be extended nor be penalized for more days than the new
+        // maximum. Perpetually locked NFTs however hold veNFT's for the maximum lock duration in
+        // effect when they were locked for as long as they remain locked, so the maximum may not
+        // be lowered until every one of them is unlocked.
+
+        require!(
+            max_lock_duration_in_days >= self.max_lock_duration_in_days
+                || self.perpetual_venft_supply == 0,
+            MaxLockDurationInUse
+        );
+
+        self.min_lock_duration_in_days = min_lock_duration_in_days;
+        self.max_lock_duration_in_days = max_lock_duration_in_days;
+
+        Ok(())
+    }
+
+    pub fn set_paused(&mut self, paused_instructions: u64) -> Result<()> {
+        require!(paused_instructions & !PAUSE_ALL == 0, UnknownPauseFlags);
+
+        self.paused_instructions = paused_instructions;
+
+        Ok(())
+    }
+
+    pub fn assert_not_paused(&self, instruction: u64) -> Result<()> {
+        require!(
+            self.paused_instructions & instruction == 0,
+            InstructionPaused
+        );
+
+        Ok(())
+    }
+
+    pub fn is_sunset(&self) -> bool {
+        self.sunset_at != 0
+    }
+
+    pub fn sunset(&mut self, current_time: i64) -> Result<()> {
+        self.assert_not_sunset()?;
+
+        self.sunset_at = current_time.max(1);
+
+        Ok(())
+    }
+
+    pub fn assert_not_sunset(&self) -> Result<()> {
+        require!(!self.is_sunset(), PoolSunset);
+
+        Ok(())
+    }
+
+    pub fn is_finalized(&self) -> bool {
+        self.finalized_at != 0
+    }
+
+    /// Rewards emitted on days on which no veNFT's were in circulation are not counted as emitted
+    /// to stakers, unless they were carried forward onto a day on which veNFT's were in
+    /// circulation.
+    pub fn finalize(&mut self, reward_schedule: &RewardSchedule, current_time: i64) -> Result<()> {
+        require!(!self.is_finalized(), AlreadyFinalized);
+        require!(
+            days_between_timestamps(self.deployed_at, current_time)
+                >= MAX_DURATION_TO_EMIT_REWARDS_DAYS + FINALIZATION_GRACE_PERIOD_DAYS,
+            NotYetFinalizable
+        );
+
+        self.update(reward_schedule, current_time);
+
+        self.total_rewards_emitted = reward_schedule
+            .total_reward_emission
+            .saturating_mul(SUBUNITS_PER_REWARD_TOKEN)
+            .saturating_sub(self.rewards_pending_carry_over)
+            .saturating_sub(self.total_rewards_reclaimable);
+        self.finalized_at = current_time.max(1);
+
+        Ok(())
+    }
+
+    /// Amount of reward token subunits emitted to stakers which have yet to be claimed. Rewards
+    /// are split amongst stakers with their shares rounded down to the subunit, so this is an
+    /// upper bound on the amount of rewards stakers may still claim. The rounding dust of less
+    /// than a subunit per staker is counted as owed, and is never swept out of the treasury.
+    pub fn rewards_owed(&self) -> u64 {
+        self.total_rewards_emitted
+            .saturating_sub(self.total_rewards_claimed)
+    }
+
+    /// Amount of reward token subunits out of `treasury_balance` which may be swept once the pool
+    /// has been finalized.
+    pub fn treasury_surplus(&self, treasury_balance: u64) -> Result<u64> {
+        require!(self.is_finalized(), NotFinalized);
+
+        Ok(treasury_balance.saturating_sub(self.rewards_owed()))
+    }
+
+    pub fn set_admin_action_delay(&mut self, admin_action_delay_in_seconds: i64) -> Result<()> {
+        require!(
+            (0..=MAX_ADMIN_ACTION_DELAY_SECONDS).contains(&admin_action_delay_in_seconds),
+            InvalidAdminActionDelay
+        );
+
+        self.admin_action_delay_in_seconds = admin_action_delay_in_seconds;
+
+        Ok(())
+    }
+
+    /// Admin actions may only be applied directly rather than queued so long as the pool has no
+    /// admin action delay.
+    pub fn assert_not_timelocked(&self) -> Result<()> {
+        require!(
+            self.admin_action_delay_in_seconds == 0,
+            AdminActionTimelocked
+        );
+
+        Ok(())
+    }
+
+    /// Pausing further instructions is never timelocked so that the admin may react to an
+    /// emergency right away. Unpausing any instruction is.
+    pub fn assert_may_set_paused(&self, paused_instructions: u64) -> Result<()> {
+        if paused_instructions & self.paused_instructions != self.paused_instructions {
+            self.assert_not_timelocked()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct RewardSchedule {
+    pub state_id: Pubkey,
+
+    /// Zero until the reward schedule is sealed, after which it may no longer be written to.
+    pub sealed_at: i64,
+
+    /// Number of days from the start of the reward schedule which have been written to.
+    pub num_days_written: u64,
+    pub total_reward_emission: u64,
+
+    pub emission_curve: EmissionCurve,
+
+    /// Number of reward tokens emitted per day should the emission curve be
+    /// `EMISSION_CURVE_TABLE`. Index 0 starts on the first day after the program is deployed.
+    pub reward_per_day: [u64; 1461],
+}
+
+impl Default for RewardSchedule {
+    fn default() -> Self {
+        Self {
+            state_id: Default::default(),
+            sealed_at: Default::default(),
+            num_days_written: Default::default(),
+            total_reward_emission: Default::default(),
+            emission_curve: Default::default(),
+            reward_per_day: [0u64; MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize],
+        }
+    }
+}
+
+impl RewardSchedule {
+    pub fn is_sealed(&self) -> bool {
+        self.sealed_at != 0
+    }
+
+    /// Chunks may overwrite days which were previously written, but may not leave a gap of
+    /// unwritten days behind them.
+    pub fn write(&mut self, day_offset: u64, reward_per_day: &[u64]) -> Result<()> {
+        require!(!self.is_sealed(), RewardScheduleSealed);
+        require!(
+            self.emission_curve.kind == EMISSION_CURVE_TABLE,
+            InvalidEmissionCurve
+        );
+
+        let end = day_offset.saturating_add(reward_per_day.len() as u64);
+        require!(
+            day_offset <= self.num_days_written && end <= MAX_DURATION_TO_EMIT_REWARDS_DAYS,
+            RewardScheduleChunkOutOfBounds
+        );
+
+        self.reward_per_day[day_offset as usize..end as usize].copy_from_slice(reward_per_day);
+        self.num_days_written = self.num_days_written.max(end);
+
+        Ok(())
+    }
+
+    pub fn set_emission_curve(&mut self, emission_curve: EmissionCurve) -> Result<()> {
+        require!(!self.is_sealed(), RewardScheduleSealed);
+
+        emission_curve.validate()?;
+        self.emission_curve = emission_curve;
+
+        Ok(())
+    }
+
+    pub fn seal(&mut self, total_reward_emission: u64, current_time: i64) -> Result<()> {
+        require!(!self.is_sealed(), RewardScheduleSealed);
+
+        let sum = match self.emission_curve.kind {
+            EMISSION_CURVE_TABLE => self
+                .reward_per_day
+                .iter()
+                .fold(0u64, |sum, reward| sum.saturating_add(*reward)),
+            _ => self
+                .emission_curve
+                .cumulative_reward(MAX_DURATION_TO_EMIT_REWARDS_DAYS),
+        };
+        require!(sum == total_reward_emission, RewardScheduleTotalMismatch);
+
+        self.total_reward_emission = total_reward_emission;
+        self.sealed_at = current_time.max(1);
+
+        Ok(())
+    }
+
+    pub fn reward_for_day(&self, day: usize) -> u64 {
+        match self.emission_curve.kind {
+            EMISSION_CURVE_TABLE => self.reward_per_day.get(day).copied().unwrap_or(0),
+            _ => self.emission_curve.reward_for_day(day as u64),
+        }
+    }
+}
+
+/// Fixed-point representation of one used to evaluate emission curves.
+const EMISSION_CURVE_ONE: u128 = 1 << 48;
+
+/// Emissions of a parametric curve are derived from the number of reward tokens emitted
+/// cumulatively over the first few days of the curve. Cumulative emissions reach exactly
+/// `total_reward_emission` on the curve's last day, such that daily emissions always add up to
+/// `total_reward_emission` regardless of rounding.
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct EmissionCurve {
+    /// One of the `EMISSION_CURVE_*` kinds.
+    pub kind: u64,
+
+    pub total_reward_emission: u64,
+    /// Number of days starting from the first day after the program is deployed over which
+    /// rewards are emitted.
+    pub num_days: u64,
+
+    /// Percentage of the previous day's emissions, in basis points, which exponential decay
+    /// curves decay by each day.
+    pub decay_rate_bps: u64,
+    /// Number of days after which stepped halving curves halve their daily emissions.
+    pub halving_interval_in_days: u64,
+}
+
+impl EmissionCurve {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (1..=MAX_DURATION_TO_EMIT_REWARDS_DAYS).contains(&self.num_days)
+                || self.kind == EMISSION_CURVE_TABLE,
+            InvalidEmissionCurve
+        );
+
+        match self.kind {
+            EMISSION_CURVE_TABLE | EMISSION_CURVE_CONSTANT | EMISSION_CURVE_LINEAR_DECAY => {}
+            EMISSION_CURVE_EXPONENTIAL_DECAY => require!(
+                (1..10_000).contains(&self.decay_rate_bps),
+                InvalidEmissionCurve
+            ),
+            EMISSION_CURVE_STEPPED_HALVING => {
+                require!(self.halving_interval_in_days >= 1, InvalidEmissionCurve)
+            }
+            _ => return Err(ErrorCode::InvalidEmissionCurve.into()),
+        }
+
+        Ok(())
+    }
+
+    pub fn reward_for_day(&self, day: u64) -> u64 {
+        self.cumulative_reward(day.saturating_add(1))
+            .saturating_sub(self.cumulative_reward(day))
+    }
+
+    /// Number of reward tokens emitted over the first `num_days` days of the curve.
+    pub fn cumulative_reward(&self, num_days: u64) -> u64 {
+        let total_shape = self.shape(self.num_days);
+        if total_shape == 0 {
+            return 0;
+        }
+
+        let shape = self.shape(num_days.min(self.num_days));
+
+        ((self.total_reward_emission as u128).saturating_mul(shape) / total_shape)
+            .try_into()
+            .unwrap_or(u64::MAX)
+    }
+
+    /// An unnormalized, non-decreasing measure of the area under the curve over its first
+    /// `num_days` days which is zero for zero days.
+    fn shape(&self, num_days: u64) -> u128 {
+        let num_days = num_days as u128;
+
+        match self.kind {
+            EMISSION_CURVE_CONSTANT => num_days,
+            EMISSION_CURVE_LINEAR_DECAY => {
+                num_days.saturating_mul((2 * self.num_days as u128).saturating_sub(num_days))
+            }
+            EMISSION_CURVE_EXPONENTIAL_DECAY => {
+                let ratio = EMISSION_CURVE_ONE * (10_000 - self.decay_rate_bps.min(10_000) as u128)
+                    / 10_000;
+                EMISSION_CURVE_ONE.saturating_sub(fixed_point_pow(ratio, num_days))
+            }
+            EMISSION_CURVE_STEPPED_HALVING => {
+                let interval = self.halving_interval_in_days.max(1) as u128;
+                let num_halvings: u32 = (num_days / interval).try_into().unwrap_or(u32::MAX);
+                let num_days_since_last_halving = num_days % interval;
+
+                let emitted_before_last_halving = interval.saturating_mul(
+                    2 * EMISSION_CURVE_ONE
+                        - (2 * EMISSION_CURVE_ONE)
+                            .checked_shr(num_halvings)
+                            .unwrap_or(0),
+                );
+                let emitted_since_last_halving = num_days_since_last_halving
+                    .saturating_mul(EMISSION_CURVE_ONE.checked_shr(num_halvings).unwrap_or(0));
+
+                emitted_before_last_halving.saturating_add(emitted_since_last_halving)
+            }
+            _ => 0,
+        }
+    }
+}
+
+/// A change to a pool parameter which is queued and may only be applied once the pool's admin
+/// action delay has passed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum AdminAction {
+    SetLockDurationBounds {
+        min_lock_duration_in_days: u64,
+        max_lock_duration_in_days: u64,
+    },
+    SetPaused {
+        paused_instructions: u64,
+    },
+    SetAdminActionDelay {
+        admin_action_delay_in_seconds: i64,
+    },
+    SetZeroSupplyRewardsPolicy {
+        zero_supply_rewards_policy: u64,
+    },
+    SetEarlyUnstakePenalty {
+        early_unstake_penalty_bps: u64,
+        early_unstake_penalty_destination: u64,
+    },
+    AddAllowedCreator {
+        address: Pubkey,
+        must_be_verified: bool,
+    },
+    RemoveAllowedCreator {
+        address: Pubkey,
+    },
+    AddAllowedCollection {
+        collection_mint_id: Pubkey,
+    },
+    RemoveAllowedCollection {
+        collection_mint_id: Pubkey,
+    },
+    SetNftGatingMode {
+        nft_gating_mode: NftGatingMode,
+    },
+    WriteRewardSchedule {
+        day_offset: u64,
+        reward_per_day: Vec<u64>,
+    },
+    SetEmissionCurve {
+        emission_curve: EmissionCurve,
+    },
+    SealRewardSchedule {
+        total_reward_emission: u64,
+    },
+    Sunset,
+    ReclaimRewards {
+        destination_id: Pubkey,
+    },
+    SweepTreasury {
+        destination_id: Pubkey,
+    },
+}
+
+impl Default for AdminAction {
+    fn default() -> Self {
+        Self::SetPaused {
+            paused_instructions: 0,
+        }
+    }
+}
+
+impl AdminAction {
+    /// Serialized size of the largest admin action.
+    pub const MAX_LEN: usize = 1 + 8 + (4 + MAX_REWARD_SCHEDULE_CHUNK_LEN * 8);
+
+    /// Check that the admin action fits within a queued admin action account. Whether or not it
+    /// may be applied is only checked once it is executed.
+    pub fn validate(&self) -> Result<()> {
+        if let Self::WriteRewardSchedule { reward_per_day, .. } = self {
+            require!(
+                reward_per_day.len() <= MAX_REWARD_SCHEDULE_CHUNK_LEN,
+                RewardScheduleChunkOutOfBounds
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Token account which reward tokens reclaimed or swept by the action are transferred to.
+    pub fn destination_id(&self) -> Option<Pubkey> {
+        match self {
+            Self::ReclaimRewards { destination_id } | Self::SweepTreasury { destination_id } => {
+                Some(*destination_id)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the amount of reward token subunits to transfer out of the treasury, which holds
+    /// `treasury_balance` subunits, to the action's destination.
+    pub fn apply(
+        &self,
+        state: &mut State,
+        pool_config: &mut PoolConfig,
+        reward_schedule: &mut RewardSchedule,
+        treasury_balance: u64,
+        current_time: i64,
+    ) -> Result<u64> {
+        match self {
+            Self::SetLockDurationBounds {
+                min_lock_duration_in_days,
+                max_lock_duration_in_days,
+            } => state
+                .set_lock_duration_bounds(*min_lock_duration_in_days, *max_lock_duration_in_days)?,
+            Self::SetPaused {
+                paused_instructions,
+            } => state.set_paused(*paused_instructions)?,
+            Self::SetAdminActionDelay {
+                admin_action_delay_in_seconds,
+            } => state.set_admin_action_delay(*admin_action_delay_in_seconds)?,
+            Self::SetZeroSupplyRewardsPolicy {
+                zero_supply_rewards_policy,
+            } => state.set_zero_supply_rewards_policy(
+                reward_schedule,
+                current_time,
+                *zero_supply_rewards_policy,
+            )?,
+            Self::SetEarlyUnstakePenalty {
+                early_unstake_penalty_bps,
+                early_unstake_penalty_destination,
+            } => state.set_early_unstake_penalty(
+                *early_unstake_penalty_bps,
+                *early_unstake_penalty_destination,
+            )?,
+            Self::AddAllowedCreator {
+                address,
+                must_be_verified,
+            } => pool_config.add_allowed_creator(*address, *must_be_verified)?,
+            Self::RemoveAllowedCreator { address } => {
+                pool_config.remove_allowed_creator(*address)?
+            }
+            Self::AddAllowedCollection { collection_mint_id } => {
+                pool_config.add_allowed_collection(*collection_mint_id)?
+            }
+            Self::RemoveAllowedCollection { collection_mint_id } => {
+                pool_config.remove_allowed_collection(*collection_mint_id)?
+            }
+            Self::SetNftGatingMode { nft_gating_mode } => {
+                pool_config.nft_gating_mode = *nft_gating_mode;
+            }
+            Self::WriteRewardSchedule {
+                day_offset,
+                reward_per_day,
+            } => reward_schedule.write(*day_offset, reward_per_day)?,
+            Self::SetEmissionCurve { emission_curve } => {
+                reward_schedule.set_emission_curve(*emission_curve)?
+            }
+            Self::SealRewardSchedule {
+                total_reward_emission,
+            } => reward_schedule.seal(*total_reward_emission, current_time)?,
+            Self::Sunset => state.sunset(current_time)?,
+            Self::ReclaimRewards { .. } => {
+                state.update(reward_schedule, current_time);
+                return state.reclaim_rewards();
+            }
+            Self::SweepTreasury { .. } => return state.treasury_surplus(treasury_balance),
+        }
+
+        Ok(0)
+    }
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct QueuedAdminAction {
+    pub state_id: Pubkey,
+    pub action_id: u64,
+
+    pub queued_at: i64,
+    pub executable_at: i64,
+
+    pub action: AdminAction,
+}
+
+impl QueuedAdminAction {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + AdminAction::MAX_LEN;
+
+    pub fn assert_executable(&self, current_time: i64) -> Result<()> {
+        require!(
+            current_time >= self.executable_at,
+            AdminActionNotYetExecutable
+        );
+
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct Multisig {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u64,
+    /// Incremented every time the signers are replaced, invalidating any transactions proposed
+    /// beforehand.
+    pub signers_seqno: u32,
+    pub signer_bump: u8,
+    pub num_transactions: u64,
+}
+
+impl Multisig {
+    pub const LEN: usize = 8 + (4 + MAX_NUM_MULTISIG_SIGNERS * 32) + 8 + 4 + 1 + 8;
+
+    pub fn set_signers(&mut self, signers: Vec<Pubkey>, threshold: u64) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= MAX_NUM_MULTISIG_SIGNERS,
+            InvalidMultisigSigners
+        );
+        require!(
+            threshold >= 1 && threshold <= signers.len() as u64,
+            InvalidMultisigSigners
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(!signers[..i].contains(signer), InvalidMultisigSigners);
+        }
+
+        self.signers = signers;
+        self.threshold = threshold;
+        self.signers_seqno = self.signers_seqno.wrapping_add(1);
+
+        Ok(())
+    }
+
+    pub fn signer_index(&self, signer_id: Pubkey) -> Result<usize> {
+        self.signers
+            .iter()
+            .position(|&id| id == signer_id)
+            .ok_or_else(|| error!(ErrorCode::NotMultisigSigner))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq)]
+pub struct MultisigTransactionAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct MultisigTransaction {
+    pub multisig_id: Pubkey,
+    pub transaction_id: u64,
+
+    pub program_id: Pubkey,
+    pub accounts: Vec<MultisigTransactionAccount>,
+    pub data: Vec<u8>,
+
+    /// Whether or not each of the multisig's signers approved the transaction.
+    pub approvals: Vec<bool>,
+    pub signers_seqno: u32,
+    pub executed: bool,
+}
+
+impl MultisigTransaction {
+    pub fn len(num_signers: usize, num_accounts: usize, data_len: usize) -> usize {
+        8 + 32
+            + 8
+            + 32
+            + (4 + num_accounts * (32 + 1 + 1))
+            + (4 + data_len)
+            + (4 + num_signers)
+            + 4
+            + 1
+    }
+
+    fn assert_pending(&self, multisig: &Multisig) -> Result<()> {
+        require!(
+            self.signers_seqno == multisig.signers_seqno,
+            MultisigSignersChanged
+        );
+        require!(!self.executed, MultisigTransactionAlreadyExecuted);
+
+        Ok(())
+    }
+
+    pub fn approve(&mut self, multisig: &Multisig, signer_index: usize) -> Result<()> {
+        self.assert_pending(multisig)?;
+
+        self.approvals[signer_index] = true;
+
+        Ok(())
+    }
+
+    pub fn assert_executable(&self, multisig: &Multisig) -> Result<()> {
+        self.assert_pending(multisig)?;
+
+        let num_approvals = self.approvals.iter().filter(|&&approved| approved).count();
+        require!(
+            num_approvals as u64 >= multisig.threshold,
+            MultisigThresholdNotReached
+        );
+
+        Ok(())
+    }
+}
+
+/// Raise `base`, a fixed-point number no greater than `EMISSION_CURVE_ONE`, to the power of
+/// `exponent`.
+fn fixed_point_pow(mut base: u128, mut exponent: u128) -> u128 {
+    let mut result = EMISSION_CURVE_ONE;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base / EMISSION_CURVE_ONE;
+        }
+        base = base * base / EMISSION_CURVE_ONE;
+        exponent >>= 1;
+    }
+    result
+}
+
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct Staker {
+    pub staker_id: Pubkey,
+
+    /// veNFT's held on the day the staker was last updated, and the amount by which the balance
+    /// changed from the day before.
+    pub venft_balance: u64,
+    pub venft_balance_change: i64,
+    /// Amount by which `venft_balance_change` changes on each of the days after the staker was
+    /// last updated, indexed by day modulo `STAKER_LEDGER_LEN`. Days on which it changes are
+    /// flagged in `venft_breakpoints`, in between which the staker's balance changes linearly.
+    pub venft_balance_change_deltas: [i64; 192],
+    pub venft_breakpoints: [u64; 3],
+    /// veNFT's held every day in exchange for perpetually locked NFTs, on top of `venft_balance`.
+    pub perpetual_venft_balance: u64,
+
+    pub num_locked_nfts: u64,
+    /// Amount of reward token subunits claimable by the staker, and the fraction of a subunit
+    /// accrued on top of it scaled by `REWARD_INDEX_PRECISION`.
+    pub num_rewards_claimable: u64,
+    pub reward_remainder: u64,
+    /// Amount of reward token subunits of early unstake penalties which were not covered by
+    /// `num_rewards_claimable` at the time, forfeited out of rewards claimable later on.
+    pub num_rewards_forfeit_owed: u64,
+
+    pub last_updated_at: i64,
+    pub last_claimed_at: i64,
+
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Default for Staker {
+    fn default() -> Self {
+        Self {
+            staker_id: Default::default(),
+            venft_balance: Default::default(),
+            venft_balance_change: Default::default(),
+            venft_balance_change_deltas: [0i64; STAKER_LEDGER_LEN as usize],
+            venft_breakpoints: [0u64; STAKER_LEDGER_LEN as usize / 64],
+            perpetual_venft_balance: Default::default(),
+            num_locked_nfts: Default::default(),
+            num_rewards_claimable: Default::default(),
+            reward_remainder: Default::default(),
+            num_rewards_forfeit_owed: Default::default(),
+            last_updated_at: Default::default(),
+            last_claimed_at: Default::default(),
+            bump: Default::default(),
+            padding: Default::default(),
+        }
+    }
+}
+
+impl Staker {
+    /// Accrue rewards for every day which has elapsed since the staker was last updated. The pool
+    /// must be updated beforehand, so that the reward per veNFT index is checkpointed up until
+    /// `current_time`.
+    pub fn update(&mut self, state: &State, current_time: i64) {
+        // Balances are indexed by day since the program was deployed, so count the number of
+        // day boundaries crossed rather than the number of full days elapsed.
+
+        let last_day = days_between_timestamps(state.deployed_at, self.last_updated_at);
+        let current_day = days_between_timestamps(state.deployed_at, current_time);
+
+        if current_day <= last_day {
+            return;
+        }
+
+        self.last_updated_at = current_time;
+
+        // The staker's balance changes linearly in between breakpoints, so rewards are accrued
+        // over every span of days in between breakpoints at once.
+
+        let last_breakpoint_day = last_day.saturating_add(STAKER_LEDGER_LEN - 1);
+        let mut day = last_day;
+
+        while day < current_day {
+            let breakpoint = self.next_venft_breakpoint(day, current_day.min(last_breakpoint_day));
+            let next_day = breakpoint.unwrap_or(current_day);
+
+            let reward_per_venft = state.reward_per_venft(day as usize, next_day as usize);
+            let day_weighted_reward_per_venft =
+                state.day_weighted_reward_per_venft(day as usize, next_day as usize);
+
+            // Rewards are the sum of (balance + (d - day) * change) * reward_per_venft[d] over
+            // each day d, which is computed modulo 2^128 as it never exceeds the rewards emitted.
+
+            let change = self.venft_balance_change;
+            self.accrue_scaled_rewards(
+                (self.venft_balance as u128)
+                    .wrapping_mul(reward_per_venft)
+                    .wrapping_add(
+                        (change as i128 as u128).wrapping_mul(
+                            day_weighted_reward_per_venft
+                                .wrapping_sub((day as u128).wrapping_mul(reward_per_venft)),
+                        ),
+                    ),
+            );
+
+            let delta = match breakpoint {
+                Some(breakpoint) => self.take_venft_balance_change_delta(breakpoint),
+                None => 0,
+            };
+            self.venft_balance_change = change.saturating_add(delta);
+            self.venft_balance = (self.venft_balance as i128
+                + (next_day - day) as i128 * change as i128
+                + delta as i128)
+                .clamp(0, u64::MAX as i128) as u64;
+
+            day = next_day;
+        }
+
+        // Perpetual veNFT's do not decay, so rewards for them are accrued over every day which
+        // has elapsed at once.
+
+        let reward_per_venft: u128 =
+            state.reward_per_venft(last_day as usize, current_day as usize);
+        self.accrue_rewards(self.perpetual_venft_balance, reward_per_venft);
+    }
+
+    /// Decaying veNFT's held by the staker on a day, counted from the day the program was
+    /// deployed, as of the last time the staker was updated. Days before the staker was last
+    /// updated are not tracked.
+    pub fn venft_balance_on_day(&self, state: &State, day: u64) -> u64 {
+        let last_day = days_between_timestamps(state.deployed_at, self.last_updated_at);
+        if day < last_day {
+            return 0;
+        }
+
+        let last_breakpoint_day = last_day.saturating_add(STAKER_LEDGER_LEN - 1);
+        let mut balance = self.venft_balance as i128;
+        let mut change = self.venft_balance_change as i128;
+        let mut current_day = last_day;
+
+        while let Some(next_day) =
+            self.next_venft_breakpoint(current_day, day.min(last_breakpoint_day))
+        {
+            let delta =
+                self.venft_balance_change_deltas[(next_day % STAKER_LEDGER_LEN) as usize] as i128;
+            balance += (next_day - current_day) as i128 * change + delta;
+            change += delta;
+            current_day = next_day;
+        }
+
+        balance += (day - current_day) as i128 * change;
+        balance.clamp(0, u64::MAX as i128) as u64
+    }
+
+    /// First day after `after_day` and no later than `until_day` on which the daily change in the
+    /// staker's veNFT balance changes.
+    fn next_venft_breakpoint(&self, after_day: u64, until_day: u64) -> Option<u64> {
+        let mut day = after_day.saturating_add(1);
+
+        while day <= until_day {
+            let index = day % STAKER_LEDGER_LEN;
+            let breakpoints = self.venft_breakpoints[(index / 64) as usize] >> (index % 64);
+            if breakpoints != 0 {
+                let day = day + breakpoints.trailing_zeros() as u64;
+                return if day <= until_day { Some(day) } else { None };
+            }
+            day += 64 - index % 64;
+        }
+
+        None
+    }
+
+    fn add_venft_balance_change_delta(&mut self, day: u64, delta: i64) {
+        let index = day % STAKER_LEDGER_LEN;
+        let entry = &mut self.venft_balance_change_deltas[index as usize];
+        *entry = entry.wrapping_add(delta);
+
+        let breakpoints = &mut self.venft_breakpoints[(index / 64) as usize];
+        match *entry != 0 {
+            true => *breakpoints |= 1 << (index % 64),
+            false => *breakpoints &= !(1 << (index % 64)),
+        }
+    }
+
+    fn take_venft_balance_change_delta(&mut self, day: u64) -> i64 {
+        let index = day % STAKER_LEDGER_LEN;
+        self.venft_breakpoints[(index / 64) as usize] &= !(1 << (index % 64));
+        std::mem::take(&mut self.venft_balance_change_deltas[index as usize])
+    }
+
+    /// Accrue rewards for a veNFT balance held over a span of days, given the amount of reward
+    /// token subunits emitted per veNFT over it. The fraction of a subunit left over is carried
+    /// onto the next rewards accrued, so that no rewards are lost to rounding over time.
+    pub fn accrue_rewards(&mut self, staker_venft_balance: u64, reward_per_venft: u128) {
+        self.accrue_scaled_rewards(staker_venft_balance as u128 * reward_per_venft);
+    }
+
+    /// Accrue an amount of reward token subunits scaled by `REWARD_INDEX_PRECISION`.
+    fn accrue_scaled_rewards(&mut self, scaled_reward_accrued: u128) {
+        let reward_accrued = self.reward_remainder as u128 + scaled_reward_accrued;
+
+        self.reward_remainder = (reward_accrued % REWARD_INDEX_PRECISION) as u64;
+        self.num_rewards_claimable = self.num_rewards_claimable.saturating_add(
+            (reward_accrued / REWARD_INDEX_PRECISION)
+                .try_into()
+                .unwrap_or(u64::MAX),
+        );
+    }
+
+    /// Deduct up to `max_amount_rewarded` reward token subunits from the rewards claimable by the
+    /// staker, update the timestamp denoting the last time the staker claimed rewards, and return
+    /// the amount of reward token subunits deducted.
+    pub fn claim_rewards(&mut self, current_time: i64, max_amount_rewarded: u64) -> u64 {
+        let amount_rewarded = self.num_rewards_claimable.min(max_amount_rewarded);
+        self.num_rewards_claimable -= amount_rewarded;
+        self.last_claimed_at = current_time;
+        amount_rewarded
+    }
+
+    /// Deduct the penalty for unstaking a NFT early from the rewards claimable by the staker, and
+    /// return the amount of reward token subunits forfeited. The penalty is based on the rewards
+    /// accrued for the NFT itself, including those already claimed. Whatever part of it is not
+    /// covered by the staker's claimable rewards is owed out of the rewards they accrue later on,
+    /// such that it may not be dodged by claiming rewards first. No penalty applies once the pool
+    /// is sunset.
+    pub fn forfeit_rewards(
+        &mut self,
+        state: &mut State,
+        locked_nft: &LockedNft,
+        current_time: i64,
+    ) -> u64 {
+        if state.is_sunset() {
+            return 0;
+        }
+
+        let num_rewards_forfeited = state.early_unstake_penalty(
+            locked_nft.num_rewards_accrued,
+            locked_nft.num_days_left_before_unlocked(current_time),
+        );
+        self.num_rewards_forfeit_owed = self
+            .num_rewards_forfeit_owed
+            .saturating_add(num_rewards_forfeited);
+        self.pay_rewards_forfeit_owed(state);
+
+        num_rewards_forfeited
+    }
+
+    /// Forfeit as much of the rewards owed for early unstake penalties as the staker has
+    /// claimable, and return the amount of reward token subunits forfeited. Must be called before
+    /// the staker claims rewards.
+    pub fn pay_rewards_forfeit_owed(&mut self, state: &mut State) -> u64 {
+        let num_rewards_forfeited = self
+            .num_rewards_forfeit_owed
+            .min(self.num_rewards_claimable);
+        self.num_rewards_forfeit_owed -= num_rewards_forfeited;
+        self.num_rewards_claimable -= num_rewards_forfeited;
+        state.forfeit_rewards(num_rewards_forfeited);
+        num_rewards_forfeited
+    }
+
+    pub fn stake_nft(&mut self, state: &mut State) {
+        self.stake_nfts(state, 1);
+    }
+
+    pub fn stake_nfts(&mut self, state: &mut State, num_nfts: u64) {
+        self.num_locked_nfts = self.num_locked_nfts.saturating_add(num_nfts);
+        state.total_num_locked_nfts = state.total_num_locked_nfts.saturating_add(num_nfts);
+    }
+
+    pub fn unstake_nfts(&mut self, state: &mut State, num_nfts: u64) {
+        self.num_locked_nfts = self.num_locked_nfts.saturating_sub(num_nfts);
+        state.total_num_locked_nfts = state.total_num_locked_nfts.saturating_sub(num_nfts);
+    }
+
+    /// Unstake every NFT whose lock has elapsed amongst the locked NFT's provided alongside their
+    /// mints, burning the veNFT's minted in exchange for them. NFT's which remain locked, or which
+    /// were not locked by the staker, are skipped. Returns whether or not each NFT was unstaked.
+    pub fn unstake_unlocked_nfts<'a>(
+        &mut self,
+        state: &mut State,
+        locked_nfts: impl IntoIterator<Item = (Pubkey, &'a mut LockedNft)>,
+        current_time: i64,
+    ) -> Vec<bool> {
+        let unstaked: Vec<bool> = locked_nfts
+            .into_iter()
+            .map(|(mint_id, locked_nft)| {
+                if !locked_nft.may_be_unlocked(self.staker_id, mint_id, current_time) {
+                    return false;
+                }
+                self.burn_venfts(state, locked_nft, current_time);
+                true
+            })
+            .collect();
+
+        let num_unstaked = unstaked.iter().filter(|&&unstaked| unstaked).count();
+        self.unstake_nfts(state, num_unstaked as u64);
+
+        unstaked
+    }
+
+    /// veNFT's are minted to the staker in exchange for the escrow of a NFT. The veNFT's will only
+    /// be considered to be part of the total veNFT supply starting from the next day. Returns the
+    /// number of veNFT's minted, which decays by one veNFT per day. Any veNFT's previously minted
+    /// in exchange for the NFT must have been burned beforehand.
+    pub fn mint_venfts(
+        &mut self,
+        state: &mut State,
+        locked_nft: &mut LockedNft,
+        current_time: i64,
+        lock_duration_in_days: u64,
+    ) -> Result<u64> {
+        // First, we assert that the lock duration in days provided is acceptable.
+
+        state.assert_lock_duration_allowed(current_time, lock_duration_in_days)?;
+
+        let days_elapsed_since_program_deployed: u64 =
+            days_between_timestamps(state.deployed_at, current_time);
+
+        // Second, we mint veNFT's to the staker over the lock duration in days specified. They
+        // decay by one veNFT over what is left of the current day, and by one veNFT every day
+        // after that. All veNFT's gets accounted for in the total veNFT supply, and in the ledger
+        // of the locked NFT they were minted in exchange for as well.
+
+        const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+        let seconds_left_before_next_day: u64 = SECONDS_PER_DAY
+            .saturating_sub(current_time.try_into().unwrap_or(u64::MAX) % SECONDS_PER_DAY);
+
+        let first_day_decay_rate =
+            VENFTS_PER_DAY.saturating_mul(seconds_left_before_next_day) / SECONDS_PER_DAY;
+
+        let venft_amount_minted = lock_duration_in_days.saturating_mul(VENFTS_PER_DAY);
+
+        locked_nft.settle_rewards(state, days_elapsed_since_program_deployed);
+        locked_nft.venft_start_day = days_elapsed_since_program_deployed;
+        locked_nft.venft_start_balance = venft_amount_minted;
+        locked_nft.venft_decaying_balance =
+            venft_amount_minted.saturating_sub(first_day_decay_rate);
+        locked_nft.venft_end_day = days_elapsed_since_program_deployed
+            .saturating_add(lock_duration_in_days)
+            .saturating_add(1);
+
+        self.add_venfts(state, locked_nft, days_elapsed_since_program_deployed, true);
+
+        Ok(venft_amount_minted)
+    }
+
+    /// Add the decaying veNFT's held in exchange for a locked NFT from `start_day` onwards to, or
+    /// remove them from, both the staker's balance and the total veNFT supply.
+    fn add_venfts(&mut self, state: &mut State, locked_nft: &LockedNft, start_day: u64, add: bool) {
+        for day in start_day.max(locked_nft.venft_start_day)..locked_nft.venft_end_day {
+            if let Some(supply) = state.venft_supply.get_mut(day as usize) {
+                *supply = match add {
+                    true => (*supply).saturating_add(locked_nft.venft_balance(day)),
+                    false => (*supply).saturating_sub(locked_nft.venft_balance(day)),
+                };
+            }
+        }
+
+        // The staker's balance is only tracked through the days on which its daily change
+        // changes. For the locked NFT's veNFT's, these are the days following the day they start
+        // being added, the day they were minted, and the day they run out.
+
+        let staker_day = days_between_timestamps(state.deployed_at, self.last_updated_at);
+        let sign: i128 = if add { 1 } else { -1 };
+        let balance = |day: Option<u64>| match day {
+            Some(day) if day >= start_day => locked_nft.venft_balance(day) as i128,
+            _ => 0,
+        };
+
+        let balance_today = balance(Some(staker_day));
+        let change_today = balance_today - balance(staker_day.checked_sub(1));
+        self.venft_balance =
+            (self.venft_balance as i128 + sign * balance_today).clamp(0, u64::MAX as i128) as u64;
+        self.venft_balance_change = (self.venft_balance_change as i128 + sign * change_today)
+            .clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+
+        let mut breakpoints = [
+            start_day,
+            start_day.saturating_add(1),
+            locked_nft.venft_start_day.saturating_add(1),
+            locked_nft.venft_start_day.saturating_add(2),
+            locked_nft.venft_end_day,
+            locked_nft.venft_end_day.saturating_add(1),
+        ];
+        breakpoints.sort_unstable();
+
+        let mut previous_day = staker_day;
+        for day in breakpoints {
+            if day <= previous_day || day >= staker_day.saturating_add(STAKER_LEDGER_LEN) {
+                continue;
+            }
+            previous_day = day;
+
+            let delta =
+                balance(Some(day)) - 2 * balance(day.checked_sub(1)) + balance(day.checked_sub(2));
+            if delta != 0 {
+                self.add_venft_balance_change_delta(day, (sign * delta) as i64);
+            }
+        }
+    }
+
+    /// Burn every veNFT minted in exchange for a locked NFT from the current day onwards, from
+    /// both the staker's balance and the total veNFT supply. veNFT's of days which have already
+    /// elapsed are left untouched, as rewards have already been emitted for them.
+    pub fn burn_venfts(
+        &mut self,
+        state: &mut State,
+        locked_nft: &mut LockedNft,
+        current_time: i64,
+    ) {
+        let days_elapsed_since_program_deployed: u64 =
+            days_between_timestamps(state.deployed_at, current_time);
+
+        locked_nft.settle_rewards(state, days_elapsed_since_program_deployed);
+        self.add_venfts(
+            state,
+            locked_nft,
+            days_elapsed_since_program_deployed,
+            false,
+        );
+
+        locked_nft.venft_end_day = locked_nft
+            .venft_end_day
+            .min(days_elapsed_since_program_deployed);
+
+        let perpetual_burn_amount = std::mem::take(&mut locked_nft.perpetual_venft_balance);
+        self.perpetual_venft_balance = self
+            .perpetual_venft_balance
+            .saturating_sub(perpetual_burn_amount);
+        state.perpetual_venft_supply = state
+            .perpetual_venft_supply
+            .saturating_sub(perpetual_burn_amount);
+    }
+
+    /// Extend the lock of a NFT by `num_days_extended` days. veNFT's minted for the NFT are burned
+    /// from the current day onwards and minted anew over the remainder of the extended lock, such
+    /// that they keep decaying by one veNFT per day until the NFT unlocks. Returns the number of
+    /// veNFT's added to the staker's balance for the current day.
+    pub fn relock_venfts(
+        &mut self,
+        state: &mut State,
+        locked_nft: &mut LockedNft,
+        current_time: i64,
+        num_days_extended: u64,
+    ) -> Result<u64> {
+        let lock_duration_in_days = locked_nft
+            .num_days_left_before_unlocked(current_time)
+            .saturating_add(num_days_extended);
+        state.assert_lock_duration_allowed(current_time, lock_duration_in_days)?;
+
+        let day = days_between_timestamps(state.deployed_at, current_time);
+        let venft_balance_before = locked_nft.venft_balance(day);
+
+        self.burn_venfts(state, locked_nft, current_time);
+        locked_nft.extend_lock_duration(current_time, num_days_extended);
+        self.mint_venfts(state, locked_nft, current_time, lock_duration_in_days)?;
+
+        Ok(locked_nft
+            .venft_balance(day)
+            .saturating_sub(venft_balance_before))
+    }
+
+    /// Perpetually lock a NFT, such that it counts as locked for the pool's maximum lock duration
+    /// for as long as it remains perpetually locked. veNFT's minted for the NFT which decay are
+    /// burned from the current day onwards in favor of veNFT's which do not decay. Returns the
+    /// number of veNFT's held every day in exchange for the NFT, which is fixed at the maximum
+    /// lock duration in effect at the time and does not follow later changes to it.
+    pub fn lock_perpetually(
+        &mut self,
+        state: &mut State,
+        locked_nft: &mut LockedNft,
+        current_time: i64,
+    ) -> Result<u64> {
+        require!(!locked_nft.is_perpetual(), PerpetuallyLocked);
+
+        self.burn_venfts(state, locked_nft, current_time);
+
+        let venft_amount = state
+            .max_lock_duration_in_days
+            .saturating_mul(VENFTS_PER_DAY);
+        locked_nft.perpetual_venft_balance = venft_amount;
+        self.perpetual_venft_balance = self.perpetual_venft_balance.saturating_add(venft_amount);
+        state.perpetual_venft_supply = state.perpetual_venft_supply.saturating_add(venft_amount);
+
+        Ok(venft_amount)
+    }
+
+    /// Stop perpetually locking a NFT. The NFT is locked for the pool's maximum lock duration
+    /// starting from the current day, over which its veNFT's decay as usual. Returns the number
+    /// of veNFT's minted in exchange for the NFT.
+    pub fn unlock_perpetually(
+        &mut self,
+        state: &mut State,
+        locked_nft: &mut LockedNft,
+        current_time: i64,
+    ) -> Result<u64> {
+        require!(locked_nft.is_perpetual(), NotPerpetuallyLocked);
+
+        self.burn_venfts(state, locked_nft, current_time);
+
+        let days_left_before_reward_emissions_end = MAX_DURATION_TO_EMIT_REWARDS_DAYS
+            .saturating_sub(days_between_timestamps(state.deployed_at, current_time));
+        let lock_duration_in_days = state
+            .max_lock_duration_in_days
+            .min(days_left_before_reward_emissions_end);
+
+        locked_nft.locked_at = current_time;
+        locked_nft.lock_duration_in_days = lock_duration_in_days;
+
+        if lock_duration_in_days < state.min_lock_duration_in_days.max(1) {
+            return Ok(0);
+        }
+
+        self.mint_venfts(state, locked_nft, current_time, lock_duration_in_days)
+    }
+}
+
+pub fn days_between_timestamps(start: i64, end: i64) -> u64 {
+    if end <= start {
+        return 0;
+    }
+    end.saturating_sub(start)
+        .checked_div(60 * 60 * 24)
+        .unwrap_or(0i64)
+        .try_into()
+        .unwrap_or(0u64)
+}
+
+#[cfg(test)]
+#[test]
+#[allow(clippy::erasing_op)]
+pub fn test_staker_lock_duration_edge_cases() {
+    let mut state: State = Default::default();
+    let mut staker: Staker = Default::default();
+
+    // Lower bound checks.
+
+    assert!(staker
+        .mint_venfts(
+            &mut state,
+            &mut Default::default(),
+            0 * 24 * 60 * 60,
+            MIN_LOCK_DURATION_DAYS - 1
+        )
+        .is_err());
+
+    assert!(staker
+        .mint_venfts(
+            &mut state,
+            &mut Default::default(),
+            0 * 24 * 60 * 60,
+            MAX_LOCK_DURATION_DAYS + 1
+        )
+        .is_err());
+
+    assert!(staker
+        .mint_venfts(
+            &mut state,
+            &mut Default::default(),
+            MAX_DURATION_TO_EMIT_REWARDS_DAYS as i64 * 24 * 60 * 60,
+            1
+        )
+        .is_err());
+
+    // Upper bound checks.
+
+    assert!(staker
+        .mint_venfts(
+            &mut state,
+            &mut Default::default(),
+            (MAX_DURATION_TO_EMIT_REWARDS_DAYS - MAX_LOCK_DURATION_DAYS + 1) as i64 * 24 * 60 * 60,
+            MAX_LOCK_DURATION_DAYS
+        )
+        .is_err());
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_fits_in_cpi_allocation() {
+    // Staker accounts are created through a CPI into the system program, which may allocate at
+    // most 10KiB of account data.
+
+    assert!(
+        8 + std::mem::size_of::<Staker>()
+            <= anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE
+    );
+}
+
+#[cfg(test)]
+#[test]
+#[allow(clippy::erasing_op)]
+pub fn test_staker_stakes_nfts() {
+    let mut state: State = Default::default();
+    let mut staker: Staker = Default::default();
+    let reward_schedule = sealed_reward_schedule();
+
+    state.set_lock_duration_bounds(1, 365 / 2).unwrap();
+
+    state.update(&reward_schedule, 0 * 24 * 60 * 60);
+    staker.update(&state, 0 * 24 * 60 * 60);
+    staker.stake_nft(&mut state);
+
+    assert_eq!(
+        staker
+            .mint_venfts(
+                &mut state,
+                &mut Default::default(),
+                0 * 24 * 60 * 60,
+                365 / 2
+            )
+            .unwrap(),
+        182000000000
+    );
+
+    assert_eq!(
+        &state.venft_supply[0..5],
+        &[
+            182000000000,
+            181000000000,
+            180000000000,
+            179000000000,
+            178000000000,
+        ]
+    );
+
+    assert_eq!(
+        (0..5)
+            .map(|day| staker.venft_balance_on_day(&state, day))
+            .collect::<Vec<_>>(),
+        &[
+            182000000000,
+            181000000000,
+            180000000000,
+            179000000000,
+            178000000000,
+        ]
+    );
+
+    state.update(&reward_schedule, 5 * 24 * 60 * 60);
+    staker.update(&state, 5 * 24 * 60 * 60);
+
+    assert_eq!(state.last_updated_at, 5 * 24 * 60 * 60);
+    assert_eq!(staker.last_updated_at, 5 * 24 * 60 * 60);
+
+    assert_eq!(
+        &state.venft_supply[0..5],
+        &[
+            182000000000,
+            181000000000,
+            180000000000,
+            179000000000,
+            178000000000,
+        ]
+    );
+
+    assert_eq!(
+        (5..10)
+            .map(|day| staker.venft_balance_on_day(&state, day))
+            .collect::<Vec<_>>(),
+        &[
+            177000000000,
+            176000000000,
+            175000000000,
+            174000000000,
+            173000000000,
+        ]
+    );
+
+    // Settling the staker between updates accrues exactly what settling them day by day would
+    // have. The reward per veNFT index is rounded down, so the staker may fall a fraction of a
+    // subunit short of every reward emitted.
+
+    assert_eq!(
+        staker.num_rewards_claimable,
+        num_rewards_accrued_on_days(
+            &state,
+            (0..5).map(|day| (day, state.venft_supply[day as usize]))
+        )
+    );
+    let num_rewards_emitted =
+        reward_schedule.reward_per_day[0..5].iter().sum::<u64>() * SUBUNITS_PER_REWARD_TOKEN;
+    assert!(num_rewards_emitted - staker.num_rewards_claimable <= 1);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_stakes_many_nfts() {
+    let mut state: State = Default::default();
+    let mut staker: Staker = Default::default();
+    let mut locked_nfts: Vec<LockedNft> = (0..3).map(|_| Default::default()).collect();
+    let reward_schedule = sealed_reward_schedule();
+
+    state.update(&reward_schedule, 0);
+    staker.update(&state, 0);
+    staker.stake_nfts(&mut state, locked_nfts.len() as u64);
+
+    for (locked_nft, lock_duration_in_days) in locked_nfts.iter_mut().zip([10, 20, 30]) {
+        locked_nft.lock_duration_in_days = lock_duration_in_days;
+        staker
+            .mint_venfts(&mut state, locked_nft, 0, lock_duration_in_days)
+            .unwrap();
+    }
+
+    assert_eq!(staker.num_locked_nfts, 3);
+    assert_eq!(state.total_num_locked_nfts, 3);
+
+    // The staker's veNFT balance is the sum of the ledgers of every NFT they staked.
+
+    for day in 0..32 {
+        let ledger_total: u64 = locked_nfts
+            .iter()
+            .map(|locked_nft| locked_nft.venft_balance(day as u64))
+            .sum();
+        assert_eq!(
+            staker.venft_balance_on_day(&state, day as u64),
+            ledger_total
+        );
+        assert_eq!(state.venft_supply[day], ledger_total);
+    }
+
+    // Unstaking many NFTs at once only unstakes those whose locks have elapsed, and counters
+    // only drop by the number of NFTs actually unstaked.
+
+    for locked_nft in locked_nfts.iter_mut() {
+        locked_nft.staker_id = staker.staker_id;
+        locked_nft.mint_id = Pubkey::new_unique();
+    }
+
+    let current_time = 25 * 24 * 60 * 60;
+    state.update(&reward_schedule, current_time);
+    staker.update(&state, current_time);
+
+    let mut foreign_locked_nft = LockedNft {
+        staker_id: Pubkey::new_unique(),
+        mint_id: Pubkey::new_unique(),
+        ..Default::default()
+    };
+
+    let unstaked = staker.unstake_unlocked_nfts(
+        &mut state,
+        locked_nfts
+            .iter_mut()
+            .chain(std::iter::once(&mut foreign_locked_nft))
+            .map(|locked_nft| (locked_nft.mint_id, locked_nft)),
+        current_time,
+    );
+    assert_eq!(unstaked, vec![true, true, false, false]);
+
+    assert_eq!(staker.num_locked_nfts, 1);
+    assert_eq!(state.total_num_locked_nfts, 1);
+
+    // NFTs are skipped when provided alongside a mint other than their own.
+
+    let unstaked = staker.unstake_unlocked_nfts(
+        &mut state,
+        std::iter::once((Pubkey::new_unique(), &mut locked_nfts[2])),
+        31 * 24 * 60 * 60,
+    );
+    assert_eq!(unstaked, vec![false]);
+    assert_eq!(staker.num_locked_nfts, 1);
+
+    for day in 25..25 + STAKER_LEDGER_LEN {
+        assert_eq!(
+            staker.venft_balance_on_day(&state, day),
+            locked_nfts[2].venft_balance(day)
+        );
+    }
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_stake_many_accounts_are_validated() {
+    let program_id = ID;
+    let state_id = Pubkey::new_unique();
+    let user_id = Pubkey::new_unique();
+
+    let group = |mint_id: Pubkey| -> Vec<Pubkey> {
+        let metadata_program_id = mpl_token_metadata::id();
+        vec![
+            mint_id,
+            Pubkey::find_program_address(
+                &[
+                    mpl_token_metadata::state::PREFIX.as_bytes(),
+                    metadata_program_id.as_ref(),
+                    mint_id.as_ref(),
+                ],
+                &metadata_program_id,
+            )
+            .0,
+            anchor_spl::associated_token::get_associated_token_address(&user_id, &mint_id),
+            Pubkey::find_program_address(
+                &[b"escrow", state_id.as_ref(), mint_id.as_ref()],
+                &program_id,
+            )
+            .0,
+            Pubkey::find_program_address(
+                &[b"locked_nft", state_id.as_ref(), mint_id.as_ref()],
+                &program_id,
+            )
+            .0,
+        ]
+    };
+
+    let first_mint_id = Pubkey::new_unique();
+    let second_mint_id = Pubkey::new_unique();
+    let account_ids = [group(first_mint_id), group(second_mint_id)].concat();
+
+    let bumps = validate_stake_many_accounts(&program_id, state_id, user_id, &account_ids).unwrap();
+    assert_eq!(bumps.len(), 2);
+    assert_eq!(
+        bumps[1],
+        StakeManyBumps {
+            escrow_bump: Pubkey::find_program_address(
+                &[b"escrow", state_id.as_ref(), second_mint_id.as_ref()],
+                &program_id,
+            )
+            .1,
+            locked_nft_bump: Pubkey::find_program_address(
+                &[b"locked_nft", state_id.as_ref(), second_mint_id.as_ref()],
+                &program_id,
+            )
+            .1,
+        }
+    );
+
+    // Groups must be complete.
+
+    assert!(validate_stake_many_accounts(&program_id, state_id, user_id, &[]).is_err());
+    assert!(validate_stake_many_accounts(
+        &program_id,
+        state_id,
+        user_id,
+        &account_ids[..account_ids.len() - 1]
+    )
+    .is_err());
+
+    // Every account in a group must be derived from the group's mint.
+
+    for i in 1..STAKE_MANY_ACCOUNTS_PER_NFT {
+        let mut account_ids = account_ids.clone();
+        account_ids[STAKE_MANY_ACCOUNTS_PER_NFT + i] = group(first_mint_id)[i];
+        assert!(
+            validate_stake_many_accounts(&program_id, state_id, user_id, &account_ids).is_err()
+        );
+    }
+
+    // Accounts must be derived from the pool and the staker.
+
+    assert!(
+        validate_stake_many_accounts(&program_id, Pubkey::new_unique(), user_id, &account_ids)
+            .is_err()
+    );
+    assert!(validate_stake_many_accounts(
+        &program_id,
+        state_id,
+        Pubkey::new_unique(),
+        &account_ids
+    )
+    .is_err());
+
+    // The same NFT may not be staked twice.
+
+    let account_ids = [group(first_mint_id), group(first_mint_id)].concat();
+    assert!(validate_stake_many_accounts(&program_id, state_id, user_id, &account_ids).is_err());
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_extends_all_to_max() {
+    let mut state = State {
+        min_lock_duration_in_days: 3,
+        max_lock_duration_in_days: 30,
+        ..Default::default()
+    };
+    let mut staker: Staker = Default::default();
+    let mut locked_nfts: Vec<LockedNft> = [(0, 10), (0, 30), (5 * DAY, 28)]
+        .iter()
+        .map(|&(locked_at, lock_duration_in_days)| LockedNft {
+            locked_at,
+            lock_duration_in_days,
+            ..Default::default()
+        })
+        .collect();
+
+    // Every lock is extended such that it remains locked for the maximum lock duration, unless
+    // it would be extended by less than the minimum lock duration.
+
+    let current_time = 5 * DAY;
+    let num_days_extended: Vec<u64> = locked_nfts
+        .iter_mut()
+        .map(|locked_nft| {
+            let num_days = state.max_num_days_may_be_extended(locked_nft, current_time);
+            if num_days > 0 {
+                staker
+                    .relock_venfts(&mut state, locked_nft, current_time, num_days)
+                    .unwrap();
+            }
+            num_days
+        })
+        .collect();
+
+    assert_eq!(num_days_extended, [25, 5, 0]);
+    assert!(locked_nfts[..2]
+        .iter()
+        .all(|locked_nft| locked_nft.unlocks_at() == current_time + 30 * DAY));
+    assert_eq!(locked_nfts[2].unlocks_at(), current_time + 28 * DAY);
+
+    // Locks are not extended past the end of reward emissions.
+
+    let current_time = (MAX_DURATION_TO_EMIT_REWARDS_DAYS as i64 - 10) * DAY;
+    let locked_nft = LockedNft {
+        locked_at: current_time,
+        lock_duration_in_days: 1,
+        ..Default::default()
+    };
+    assert_eq!(
+        state.max_num_days_may_be_extended(&locked_nft, current_time),
+        9
+    );
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_locks_nfts_perpetually() {
+    let mut state = State {
+        max_lock_duration_in_days: 30,
+        ..Default::default()
+    };
+    let mut staker: Staker = Default::default();
+    let mut locked_nft = LockedNft {
+        lock_duration_in_days: 10,
+        ..Default::default()
+    };
+    let reward_schedule = sealed_reward_schedule();
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, 0);
+    staker
+        .mint_venfts(&mut state, &mut locked_nft, 0, 10)
+        .unwrap();
+
+    // Decaying veNFT's are replaced from the current day onwards by veNFT's which do not decay.
+
+    let current_time = 2 * DAY;
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, current_time);
+    assert_eq!(
+        staker
+            .lock_perpetually(&mut state, &mut locked_nft, current_time)
+            .unwrap(),
+        30_000_000_000
+    );
+    assert!(staker
+        .lock_perpetually(&mut state, &mut locked_nft, current_time)
+        .is_err());
+
+    assert!((2..2 + STAKER_LEDGER_LEN).all(|day| staker.venft_balance_on_day(&state, day) == 0));
+    assert!(state.venft_supply[2..].iter().all(|&supply| supply == 0));
+    assert_eq!(staker.perpetual_venft_balance, 30_000_000_000);
+    assert_eq!(state.perpetual_venft_supply, 30_000_000_000);
+
+    assert_eq!(locked_nft.unlocks_at(), i64::MAX);
+    assert!(!locked_nft.may_be_unlocked(Default::default(), Default::default(), i64::MAX - 1));
+    assert_eq!(
+        state.max_num_days_may_be_extended(&locked_nft, current_time),
+        0
+    );
+
+    // Rewards accrue for every day the NFT is perpetually locked, well past the maximum lock
+    // duration.
+
+    let current_time = 100 * DAY;
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, current_time);
+
+    assert_eq!(
+        staker.num_rewards_claimable,
+        num_rewards_accrued_on_days(
+            &state,
+            (0..100).map(|day| match day {
+                0..=1 => (day, state.venft_supply[day as usize]),
+                _ => (day, 30_000_000_000),
+            })
+        )
+    );
+    let num_rewards_emitted =
+        reward_schedule.reward_per_day[..100].iter().sum::<u64>() * SUBUNITS_PER_REWARD_TOKEN;
+    assert!(num_rewards_emitted - staker.num_rewards_claimable <= 1);
+
+    // Once no longer perpetually locked, the NFT is locked for the maximum lock duration and its
+    // veNFT's decay as usual.
+
+    assert_eq!(
+        staker
+            .unlock_perpetually(&mut state, &mut locked_nft, current_time)
+            .unwrap(),
+        30_000_000_000
+    );
+    assert!(staker
+        .unlock_perpetually(&mut state, &mut locked_nft, current_time)
+        .is_err());
+
+    assert_eq!(staker.perpetual_venft_balance, 0);
+    assert_eq!(state.perpetual_venft_supply, 0);
+    assert_eq!(locked_nft.unlocks_at(), current_time + 30 * DAY);
+
+    // Rewards accrued for the NFT both before and while it was perpetually locked are settled
+    // onto it, give or take a subunit lost to rounding every time they were settled.
+
+    assert!(staker.num_rewards_claimable - locked_nft.num_rewards_accrued <= 2);
+    assert_eq!(
+        (100..103)
+            .map(|day| staker.venft_balance_on_day(&state, day))
+            .collect::<Vec<_>>(),
+        &[30_000_000_000, 29_000_000_000, 28_000_000_000]
+    );
+    assert_eq!(
+        &state.venft_supply[100..130],
+        (100..130)
+            .map(|day| staker.venft_balance_on_day(&state, day))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_unstakes_nfts_exactly() {
+    let mut state: State = Default::default();
+    let mut staker: Staker = Default::default();
+    let mut first_locked_nft: LockedNft = Default::default();
+    let mut second_locked_nft: LockedNft = Default::default();
+    let reward_schedule = sealed_reward_schedule();
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, DAY / 2);
+    first_locked_nft.locked_at = DAY / 2;
+    first_locked_nft.lock_duration_in_days = 10;
+    staker
+        .mint_venfts(&mut state, &mut first_locked_nft, DAY / 2, 10)
+        .unwrap();
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, 2 * DAY + DAY / 4);
+    staker
+        .mint_venfts(&mut state, &mut second_locked_nft, 2 * DAY + DAY / 4, 5)
+        .unwrap();
+
+    // Extending a lock mints veNFT's anew over the remainder of the extended lock, which is
+    // described in full by the locked NFT's ledger.
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, 3 * DAY + DAY / 2);
+    assert_eq!(
+        staker
+            .relock_venfts(&mut state, &mut first_locked_nft, 3 * DAY + DAY / 2, 4)
+            .unwrap(),
+        3_500_000_000
+    );
+    assert_eq!(first_locked_nft.lock_duration_in_days, 11);
+    assert_eq!(first_locked_nft.venft_balance(3), 11_000_000_000);
+    assert_eq!(first_locked_nft.venft_balance(4), 10_500_000_000);
+    assert_eq!(first_locked_nft.venft_balance(14), 500_000_000);
+    assert_eq!(first_locked_nft.venft_balance(15), 0);
+
+    for day in 3..16 {
+        assert_eq!(
+            state.venft_supply[day],
+            first_locked_nft.venft_balance(day as u64)
+                + second_locked_nft.venft_balance(day as u64)
+        );
+    }
+
+    let supply_before_unstaking = state.venft_supply;
+
+    // Unstaking the second NFT leaves behind exactly the veNFT's minted for the first NFT.
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, 8 * DAY);
+    staker.burn_venfts(&mut state, &mut second_locked_nft, 8 * DAY);
+
+    for day in 8..8 + STAKER_LEDGER_LEN {
+        let balance = staker.venft_balance_on_day(&state, day);
+        assert_eq!(second_locked_nft.venft_balance(day), 0);
+        assert_eq!(balance, first_locked_nft.venft_balance(day));
+        assert_eq!(state.venft_supply[day as usize], balance);
+    }
+    assert_eq!(&state.venft_supply[..8], &supply_before_unstaking[..8]);
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, 20 * DAY);
+    staker.burn_venfts(&mut state, &mut first_locked_nft, 20 * DAY);
+
+    assert!((20..20 + STAKER_LEDGER_LEN).all(|day| staker.venft_balance_on_day(&state, day) == 0));
+    assert!(state.venft_supply[20..].iter().all(|&supply| supply == 0));
+    assert_eq!(&state.venft_supply[..8], &supply_before_unstaking[..8]);
+
+    // Rewards settled across every change in the slope of the staker's veNFT balance match those
+    // accrued day by day.
+
+    assert_eq!(
+        staker.num_rewards_claimable,
+        num_rewards_accrued_on_days(
+            &state,
+            (0..20).map(|day| (day, state.venft_supply[day as usize]))
+        )
+    );
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_claims_rewards() {
+    let mut staker = Staker {
+        num_rewards_claimable: 100,
+        ..Default::default()
+    };
+
+    assert_eq!(staker.claim_rewards(1, 40), 40);
+    assert_eq!(staker.num_rewards_claimable, 60);
+    assert_eq!(staker.last_claimed_at, 1);
+
+    assert_eq!(staker.claim_rewards(2, 0), 0);
+    assert_eq!(staker.num_rewards_claimable, 60);
+
+    assert_eq!(staker.claim_rewards(3, u64::MAX), 60);
+    assert_eq!(staker.num_rewards_claimable, 0);
+    assert_eq!(staker.last_claimed_at, 3);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_stakers_accrue_rewards_precisely() {
+    let mut state: State = Default::default();
+    let mut stakers: Vec<Staker> = (0..37).map(|_| Default::default()).collect();
+    let reward_schedule = sealed_reward_schedule();
+
+    // Stakers lock NFTs for durations which do not evenly divide one another, so that their
+    // shares of each day's rewards are fractions of a subunit.
+
+    for (i, staker) in stakers.iter_mut().enumerate() {
+        staker
+            .mint_venfts(
+                &mut state,
+                &mut Default::default(),
+                DAY / 2,
+                (i as u64 * 7) % MAX_LOCK_DURATION_DAYS + 1,
+            )
+            .unwrap();
+    }
+
+    let current_time = (MAX_LOCK_DURATION_DAYS as i64 + 2) * DAY;
+    state.update(&reward_schedule, current_time);
+
+    let num_rewards_paid_out: u64 = stakers
+        .iter_mut()
+        .map(|staker| {
+            staker.update(&state, current_time);
+            staker.claim_rewards(current_time, u64::MAX)
+        })
+        .sum();
+
+    // Rewards emitted before the first day the veNFT's count towards the supply are carried
+    // forward onto it. Each staker loses less than a single subunit to rounding overall.
+
+    let num_rewards_emitted: u64 = (0..=MAX_LOCK_DURATION_DAYS as usize)
+        .filter(|&day| state.venft_supply[day] != 0)
+        .map(|day| state.reward_for_day(&reward_schedule, day))
+        .sum();
+
+    assert!(num_rewards_paid_out <= num_rewards_emitted);
+    assert!(num_rewards_emitted - num_rewards_paid_out < stakers.len() as u64);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_unstakes_nfts_early() {
+    let mut state = State {
+        max_lock_duration_in_days: 100,
+        ..Default::default()
+    };
+    assert!(state.set_early_unstake_penalty(10_001, 0).is_err());
+    assert!(state.set_early_unstake_penalty(5_000, 2).is_err());
+
+    let locked_nft = |num_rewards_accrued: u64, num_days_left_before_unlocked: u64| LockedNft {
+        num_rewards_accrued,
+        lock_duration_in_days: num_days_left_before_unlocked,
+        ..Default::default()
+    };
+
+    // No penalty is applied by default.
+
+    let mut staker = Staker {
+        num_rewards_claimable: 1_000,
+        ..Default::default()
+    };
+    assert_eq!(
+        staker.forfeit_rewards(&mut state, &locked_nft(1_000, 100), 0),
+        0
+    );
+    assert_eq!(staker.num_rewards_claimable, 1_000);
+
+    // The penalty scales with the number of days left before the NFT is unlocked, capped at the
+    // maximum lock duration. Forfeited rewards are set aside to be reclaimed.
+
+    state
+        .set_early_unstake_penalty(5_000, EARLY_UNSTAKE_PENALTY_TO_TREASURY)
+        .unwrap();
+    assert_eq!(state.early_unstake_penalty(1_000, 0), 0);
+    assert_eq!(state.early_unstake_penalty(1_000, 50), 250);
+    assert_eq!(state.early_unstake_penalty(1_000, 1_000), 500);
+    assert_eq!(state.early_unstake_penalty(u64::MAX, 100), u64::MAX / 2);
+
+    assert_eq!(
+        staker.forfeit_rewards(&mut state, &locked_nft(1_000, 20), 0),
+        100
+    );
+    assert_eq!(staker.num_rewards_claimable, 900);
+    assert_eq!(state.total_rewards_reclaimable, 100);
+    assert_eq!(state.rewards_pending_carry_over, 0);
+
+    // Forfeited rewards are otherwise carried forward to the other stakers.
+
+    state
+        .set_early_unstake_penalty(10_000, EARLY_UNSTAKE_PENALTY_TO_STAKERS)
+        .unwrap();
+    assert_eq!(
+        staker.forfeit_rewards(&mut state, &locked_nft(900, 100), 0),
+        900
+    );
+    assert_eq!(staker.num_rewards_claimable, 0);
+    assert_eq!(state.total_rewards_reclaimable, 100);
+    assert_eq!(state.rewards_pending_carry_over, 900);
+    assert_eq!(state.total_rewards_forfeited, 1_000);
+
+    // Penalties not covered by the rewards claimable by the staker are forfeited out of the
+    // rewards they accrue later on.
+
+    assert_eq!(
+        staker.forfeit_rewards(&mut state, &locked_nft(100, 100), 0),
+        100
+    );
+    assert_eq!(staker.num_rewards_forfeit_owed, 100);
+    assert_eq!(state.total_rewards_forfeited, 1_000);
+
+    staker.num_rewards_claimable = 60;
+    assert_eq!(staker.pay_rewards_forfeit_owed(&mut state), 60);
+    assert_eq!(staker.num_rewards_claimable, 0);
+    assert_eq!(staker.num_rewards_forfeit_owed, 40);
+    assert_eq!(state.rewards_pending_carry_over, 960);
+
+    // No penalty applies once the pool has been sunset.
+
+    state.sunset(1).unwrap();
+    assert_eq!(
+        staker.forfeit_rewards(&mut state, &locked_nft(1_000, 100), 0),
+        0
+    );
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_claims_rewards_before_unstaking_early() {
+    let mut state = State {
+        max_lock_duration_in_days: 100,
+        ..Default::default()
+    };
+    let mut staker: Staker = Default::default();
+    let mut short_locked_nft: LockedNft = Default::default();
+    let mut long_locked_nft: LockedNft = Default::default();
+    let reward_schedule = sealed_reward_schedule();
+
+    state
+        .set_early_unstake_penalty(5_000, EARLY_UNSTAKE_PENALTY_TO_TREASURY)
+        .unwrap();
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, DAY / 2);
+    staker.stake_nfts(&mut state, 2);
+    for (locked_nft, lock_duration_in_days) in
+        [(&mut short_locked_nft, 10), (&mut long_locked_nft, 100)]
+    {
+        locked_nft.locked_at = DAY / 2;
+        locked_nft.lock_duration_in_days = lock_duration_in_days;
+        staker
+            .mint_venfts(&mut state, locked_nft, DAY / 2, lock_duration_in_days)
+            .unwrap();
+    }
+
+    // Each NFT accrues rewards for its own veNFT's, which together add up to the rewards accrued
+    // by the staker, give or take a subunit lost to rounding per NFT.
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, 5 * DAY + DAY / 2);
+    staker.burn_venfts(&mut state, &mut long_locked_nft, 5 * DAY + DAY / 2);
+    short_locked_nft.settle_rewards(&state, 5);
+
+    assert_eq!(
+        long_locked_nft.num_rewards_accrued,
+        num_rewards_accrued_on_days(
+            &state,
+            (0..5).map(|day| (day, long_locked_nft.venft_balance(day)))
+        )
+    );
+    let num_rewards_accrued =
+        short_locked_nft.num_rewards_accrued + long_locked_nft.num_rewards_accrued;
+    assert!(staker.num_rewards_claimable - num_rewards_accrued <= 2);
+
+    // Claiming every reward before unstaking early does not dodge the penalty, which only
+    // considers the rewards accrued for the NFT unstaked. The NFT may still be unstaken, with the
+    // penalty owed out of the rewards accrued for the staker's other NFTs.
+
+    let num_rewards_forfeited = state.early_unstake_penalty(
+        long_locked_nft.num_rewards_accrued,
+        long_locked_nft.num_days_left_before_unlocked(5 * DAY + DAY / 2),
+    );
+    assert!(num_rewards_forfeited > 0);
+    assert!(num_rewards_forfeited < staker.num_rewards_claimable / 2);
+
+    staker.claim_rewards(5 * DAY + DAY / 2, u64::MAX);
+    assert_eq!(
+        staker.forfeit_rewards(&mut state, &long_locked_nft, 5 * DAY + DAY / 2),
+        num_rewards_forfeited
+    );
+    staker.unstake_nfts(&mut state, 1);
+    assert_eq!(staker.num_locked_nfts, 1);
+    assert_eq!(staker.num_rewards_forfeit_owed, num_rewards_forfeited);
+    assert_eq!(state.total_rewards_reclaimable, 0);
+
+    // Once enough rewards have accrued for the staker's other NFTs, the penalty is paid before
+    // they may be claimed.
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, 9 * DAY + DAY / 2);
+    let num_rewards_claimable = staker.num_rewards_claimable;
+    assert!(num_rewards_claimable >= num_rewards_forfeited);
+    assert_eq!(
+        staker.pay_rewards_forfeit_owed(&mut state),
+        num_rewards_forfeited
+    );
+    assert_eq!(
+        staker.claim_rewards(9 * DAY + DAY / 2, u64::MAX),
+        num_rewards_claimable - num_rewards_forfeited
+    );
+    assert_eq!(staker.num_rewards_forfeit_owed, 0);
+    assert_eq!(state.total_rewards_reclaimable, num_rewards_forfeited);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_stakes_nfts_at_end() {
+    let mut state: State = Default::default();
+    let mut staker: Staker = Default::default();
+    let reward_schedule = sealed_reward_schedule();
+
+    state.update(&reward_schedule, 1457 * 24 * 60 * 60);
+    staker.update(&state, 1457 * 24 * 60 * 60);
+    staker.stake_nft(&mut state);
+    staker
+        .mint_venfts(&mut state, &mut Default::default(), 1457 * 24 * 60 * 60, 4)
+        .unwrap();
+
+    assert_eq!(
+        &state.venft_supply[1457..],
+        &[4000000000, 3000000000, 2000000000, 1000000000]
+    );
+
+    assert_eq!(
+        (1457..1461)
+            .map(|day| staker.venft_balance_on_day(&state, day))
+            .collect::<Vec<_>>(),
+        &[4000000000, 3000000000, 2000000000, 1000000000]
+    );
+
+    state.update(&reward_schedule, 1600 * 24 * 60 * 60);
+    staker.update(&state, 1600 * 24 * 60 * 60);
+
+    assert_eq!(state.last_updated_at, 1600 * 24 * 60 * 60);
+    assert_eq!(staker.last_updated_at, 1600 * 24 * 60 * 60);
+
+    assert_eq!(
+        &state.venft_supply[1457..],
+        &[4000000000, 3000000000, 2000000000, 1000000000]
+    );
+
+    assert!(
+        (1600..1600 + STAKER_LEDGER_LEN).all(|day| staker.venft_balance_on_day(&state, day) == 0)
+    );
+
+    // Only rewards emitted on the last 4 days accrue to the staker, less rounding.
+
+    let num_rewards_emitted =
+        reward_schedule.reward_per_day[1457..].iter().sum::<u64>() * SUBUNITS_PER_REWARD_TOKEN;
+    assert!(staker.num_rewards_claimable <= num_rewards_emitted);
+    assert!(num_rewards_emitted - staker.num_rewards_claimable <= 1);
+    assert_eq!(
+        state.total_rewards_reclaimable,
+        reward_schedule.reward_per_day[..1457].iter().sum::<u64>() * SUBUNITS_PER_REWARD_TOKEN
+    );
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_staker_stakes_nfts_at_end_with_rewards_carried_forward() {
+    let mut state: State = Default::default();
+    let mut staker: Staker = Default::default();
+    let reward_schedule = sealed_reward_schedule();
+
+    state
+        .set_zero_supply_rewards_policy(&reward_schedule, 0, ZERO_SUPPLY_REWARDS_CARRY_FORWARD)
+        .unwrap();
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, 1457 * DAY);
+    staker.stake_nft(&mut state);
+    staker
+        .mint_venfts(&mut state, &mut Default::default(), 1457 * DAY, 4)
+        .unwrap();
+
+    update_state_and_staker(&mut state, &mut staker, &reward_schedule, 1600 * DAY);
+
+    // Rewards emitted before any NFT was staked are carried forward onto the staker's first day.
+
+    assert_eq!(
+        staker.num_rewards_claimable,
+        num_rewards_accrued_on_days(
+            &state,
+            (1457..1461).map(|day| (day, state.venft_supply[day as usize]))
+        )
+    );
+    assert!(700_000_000 * SUBUNITS_PER_REWARD_TOKEN - staker.num_rewards_claimable <= 1);
+    assert_eq!(
+        state.total_rewards_carried_over,
+        reward_schedule.reward_per_day[..1457].iter().sum::<u64>() * SUBUNITS_PER_REWARD_TOKEN
+    );
+    assert_eq!(state.total_rewards_reclaimable, 0);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_admin_handover() {
+    let mut state: State = Default::default();
+
+    let admin_id = Pubkey::new_unique();
+    let new_admin_id = Pubkey::new_unique();
+    state.admin_id = admin_id;
+
+    // Nobody may accept the pool without a proposal.
+
+    assert!(state.accept_admin(new_admin_id).is_err());
+    assert!(state.accept_admin(Pubkey::default()).is_err());
+
+    state.propose_admin(new_admin_id);
+    assert_eq!(state.admin_id, admin_id);
+    assert_eq!(state.pending_admin_id, new_admin_id);
+
+    assert!(state.accept_admin(Pubkey::new_unique()).is_err());
+    assert!(state.accept_admin(admin_id).is_err());
+
+    state.accept_admin(new_admin_id).unwrap();
+    assert_eq!(state.admin_id, new_admin_id);
+    assert_eq!(state.pending_admin_id, Pubkey::default());
+
+    // Only the current admin passes admin-only checks.
+
+    state.assert_admin(new_admin_id).unwrap();
+    assert!(state.assert_admin(admin_id).is_err());
+    assert!(state.assert_admin(Pubkey::new_unique()).is_err());
+    assert!(state.assert_admin(Pubkey::default()).is_err());
+
+    // Proposals may be cancelled by proposing the default public key.
+
+    state.propose_admin(admin_id);
+    state.propose_admin(Pubkey::default());
+    assert!(state.accept_admin(admin_id).is_err());
+    assert_eq!(state.admin_id, new_admin_id);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_admin_sets_lock_duration_bounds() {
+    let mut state: State = Default::default();
+    let mut staker: Staker = Default::default();
+
+    assert!(state.set_lock_duration_bounds(0, 30).is_err());
+    assert!(state.set_lock_duration_bounds(31, 30).is_err());
+    assert!(state
+        .set_lock_duration_bounds(1, MAX_LOCK_DURATION_DAYS + 1)
+        .is_err());
+
+    assert_eq!(state.min_lock_duration_in_days, MIN_LOCK_DURATION_DAYS);
+    assert_eq!(state.max_lock_duration_in_days, MAX_LOCK_DURATION_DAYS);
+
+    state.set_lock_duration_bounds(7, 30).unwrap();
+
+    assert!(staker
+        .mint_venfts(&mut state, &mut Default::default(), 0, 6)
+        .is_err());
+    assert!(staker
+        .mint_venfts(&mut state, &mut Default::default(), 0, 31)
+        .is_err());
+    assert!(staker
+        .mint_venfts(&mut state, &mut Default::default(), 0, 30)
+        .is_ok());
+
+    let locked_nft = LockedNft {
+        locked_at: 0,
+        lock_duration_in_days: 30,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        locked_nft.max_num_days_may_be_extended(10 * 24 * 60 * 60, state.max_lock_duration_in_days),
+        10
+    );
+
+    // The maximum lock duration may not be lowered while NFTs are perpetually locked, though it
+    // may be raised.
+
+    let mut perpetual_locked_nft: LockedNft = Default::default();
+    staker
+        .lock_perpetually(&mut state, &mut perpetual_locked_nft, 0)
+        .unwrap();
+    assert!(state.set_lock_duration_bounds(7, 29).is_err());
+    state.set_lock_duration_bounds(7, 60).unwrap();
+
+    // NFTs locked for longer than a lowered maximum lock duration are left as they are.
+
+    staker
+        .unlock_perpetually(&mut state, &mut perpetual_locked_nft, 0)
+        .unwrap();
+    state.set_lock_duration_bounds(7, 29).unwrap();
+    assert_eq!(perpetual_locked_nft.lock_duration_in_days, 60);
+    assert_eq!(
+        perpetual_locked_nft.max_num_days_may_be_extended(0, state.max_lock_duration_in_days),
+        0
+    );
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_pools_with_different_lock_bounds() {
+    let mut casual_state: State = Default::default();
+    let mut governance_state: State = Default::default();
+    let mut casual_staker: Staker = Default::default();
+    let mut governance_staker: Staker = Default::default();
+    let reward_schedule = sealed_reward_schedule();
+
+    casual_state.set_lock_duration_bounds(1, 30).unwrap();
+    governance_state
+        .set_lock_duration_bounds(90, MAX_LOCK_DURATION_DAYS)
+        .unwrap();
+
+    assert!(casual_staker
+        .mint_venfts(&mut casual_state, &mut Default::default(), 0, 31)
+        .is_err());
+    assert!(governance_staker
+        .mint_venfts(&mut governance_state, &mut Default::default(), 0, 89)
+        .is_err());
+
+    casual_staker.stake_nft(&mut casual_state);
+    casual_staker
+        .mint_venfts(&mut casual_state, &mut Default::default(), 0, 30)
+        .unwrap();
+    governance_staker.stake_nft(&mut governance_state);
+    governance_staker
+        .mint_venfts(
+            &mut governance_state,
+            &mut Default::default(),
+            0,
+            MAX_LOCK_DURATION_DAYS,
+        )
+        .unwrap();
+
+    assert_eq!(casual_staker.venft_balance_on_day(&casual_state, 30), 0);
+    assert_eq!(
+        governance_state.venft_supply[MAX_LOCK_DURATION_DAYS as usize - 1],
+        1_000_000_000
+    );
+
+    assert!(casual_state.set_lock_duration_bounds(1, 60).is_ok());
+
+    // Staying idle for longer than the maximum lock duration accrues rewards for every day a
+    // veNFT balance was held.
+
+    let current_time = (MAX_LOCK_DURATION_DAYS as i64 + 10) * 24 * 60 * 60;
+    governance_state.update(&reward_schedule, current_time);
+    governance_staker.update(&governance_state, current_time);
+
+    let current_day = MAX_LOCK_DURATION_DAYS + 10;
+    assert!((current_day..current_day + STAKER_LEDGER_LEN)
+        .all(|day| governance_staker.venft_balance_on_day(&governance_state, day) == 0));
+    let num_rewards_emitted = reward_schedule.reward_per_day[0..MAX_LOCK_DURATION_DAYS as usize]
+        .iter()
+        .sum::<u64>()
+        * SUBUNITS_PER_REWARD_TOKEN;
+    assert_eq!(
+        governance_staker.num_rewards_claimable,
+        num_rewards_accrued_on_days(
+            &governance_state,
+            (0..MAX_LOCK_DURATION_DAYS)
+                .map(|day| (day, governance_state.venft_supply[day as usize]))
+        )
+    );
+    assert!(num_rewards_emitted - governance_staker.num_rewards_claimable <= 1);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_admin_queues_actions() {
+    let mut state: State = Default::default();
+    let mut pool_config: PoolConfig = Default::default();
+    let mut reward_schedule: RewardSchedule = Default::default();
+
+    assert!(state.assert_not_timelocked().is_ok());
+    assert!(state.set_admin_action_delay(-1).is_err());
+    assert!(state
+        .set_admin_action_delay(MAX_ADMIN_ACTION_DELAY_SECONDS + 1)
+        .is_err());
+
+    state.set_admin_action_delay(24 * 60 * 60).unwrap();
+    assert!(state.assert_not_timelocked().is_err());
+
+    // Instructions may still be paused right away, but not unpaused.
+
+    assert!(state.assert_may_set_paused(PAUSE_CLAIM_REWARDS).is_ok());
+    state.set_paused(PAUSE_CLAIM_REWARDS).unwrap();
+    assert!(state.assert_may_set_paused(PAUSE_ALL).is_ok());
+    assert!(state.assert_may_set_paused(PAUSE_STAKE).is_err());
+    assert!(state.assert_may_set_paused(0).is_err());
+
+    let queued_admin_action = QueuedAdminAction {
+        queued_at: 0,
+        executable_at: state.admin_action_delay_in_seconds,
+        action: AdminAction::SetLockDurationBounds {
+            min_lock_duration_in_days: 7,
+            max_lock_duration_in_days: 30,
+        },
+        ..Default::default()
+    };
+    assert!(queued_admin_action
+        .assert_executable(24 * 60 * 60 - 1)
+        .is_err());
+    assert!(queued_admin_action.assert_executable(24 * 60 * 60).is_ok());
+
+    let creator_id = Pubkey::new_unique();
+
+    for action in [
+        queued_admin_action.action,
+        AdminAction::SetPaused {
+            paused_instructions: PAUSE_STAKE,
+        },
+        AdminAction::AddAllowedCreator {
+            address: creator_id,
+            must_be_verified: true,
+        },
+        AdminAction::SetNftGatingMode {
+            nft_gating_mode: NftGatingMode::CreatorAndCollection,
+        },
+        AdminAction::WriteRewardSchedule {
+            day_offset: 0,
+            reward_per_day: vec![100; MAX_REWARD_SCHEDULE_CHUNK_LEN],
+        },
+        AdminAction::SetAdminActionDelay {
+            admin_action_delay_in_seconds: 0,
+        },
+    ] {
+        action.validate().unwrap();
+        action
+            .apply(&mut state, &mut pool_config, &mut reward_schedule, 0, 0)
+            .unwrap();
+    }
+
+    assert_eq!(state.min_lock_duration_in_days, 7);
+    assert_eq!(state.max_lock_duration_in_days, 30);
+    assert!(state.assert_not_paused(PAUSE_STAKE).is_err());
+    assert!(state.assert_not_paused(PAUSE_CLAIM_REWARDS).is_ok());
+    assert_eq!(
+        pool_config.allowed_creators,
+        vec![AllowedCreator {
+            address: creator_id,
+            must_be_verified: true,
+        }]
+    );
+    assert_eq!(
+        pool_config.nft_gating_mode,
+        NftGatingMode::CreatorAndCollection
+    );
+    assert_eq!(
+        reward_schedule.num_days_written,
+        MAX_REWARD_SCHEDULE_CHUNK_LEN as u64
+    );
+    assert!(state.assert_not_timelocked().is_ok());
+
+    // Rewards reclaimed or swept through queued admin actions are transferred to the destination
+    // specified when queued.
+
+    let destination_id = Pubkey::new_unique();
+    state.total_rewards_reclaimable = 1_000;
+
+    let reclaim_rewards = AdminAction::ReclaimRewards { destination_id };
+    assert_eq!(reclaim_rewards.destination_id(), Some(destination_id));
+    assert_eq!(
+        reclaim_rewards
+            .apply(&mut state, &mut pool_config, &mut reward_schedule, 0, 0)
+            .unwrap(),
+        1_000
+    );
+
+    let sweep_treasury = AdminAction::SweepTreasury { destination_id };
+    assert_eq!(sweep_treasury.destination_id(), Some(destination_id));
+    assert!(sweep_treasury
+        .apply(&mut state, &mut pool_config, &mut reward_schedule, 1_000, 0)
+        .is_err());
+
+    state.finalized_at = 1;
+    state.total_rewards_emitted = 500;
+    state.total_rewards_claimed = 100;
+    assert_eq!(
+        sweep_treasury
+            .apply(&mut state, &mut pool_config, &mut reward_schedule, 1_000, 0)
+            .unwrap(),
+        600
+    );
+
+    assert_eq!(AdminAction::Sunset.destination_id(), None);
+    assert_eq!(
+        AdminAction::Sunset
+            .apply(&mut state, &mut pool_config, &mut reward_schedule, 1_000, 0)
+            .unwrap(),
+        0
+    );
+    assert!(state.is_sunset());
+
+    // Queued admin actions must fit within their account.
+
+    let largest_action = AdminAction::WriteRewardSchedule {
+        day_offset: 0,
+        reward_per_day: vec![100; MAX_REWARD_SCHEDULE_CHUNK_LEN],
+    };
+    assert!(largest_action.try_to_vec().unwrap().len() <= AdminAction::MAX_LEN);
+
+    let oversized_action = AdminAction::WriteRewardSchedule {
+        day_offset: 0,
+        reward_per_day: vec![100; MAX_REWARD_SCHEDULE_CHUNK_LEN + 1],
+    };
+    assert!(oversized_action.validate().is_err());
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_multisig_approvals() {
+    let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+    let mut multisig: Multisig = Default::default();
+    assert!(multisig.set_signers(vec![], 1).is_err());
+    assert!(multisig.set_signers(signers.clone(), 0).is_err());
+    assert!(multisig.set_signers(signers.clone(), 4).is_err());
+    assert!(multisig
+        .set_signers(vec![signers[0], signers[1], signers[0]], 2)
+        .is_err());
+    assert!(multisig
+        .set_signers(vec![Pubkey::new_unique(); MAX_NUM_MULTISIG_SIGNERS + 1], 1)
+        .is_err());
+
+    multisig.set_signers(signers.clone(), 2).unwrap();
+    assert_eq!(multisig.signer_index(signers[2]).unwrap(), 2);
+    assert!(multisig.signer_index(Pubkey::new_unique()).is_err());
+
+    let mut transaction = MultisigTransaction {
+        approvals: vec![false; signers.len()],
+        signers_seqno: multisig.signers_seqno,
+        ..Default::default()
+    };
+
+    // Approving twice does not count twice.
+
+    transaction.approve(&multisig, 0).unwrap();
+    transaction.approve(&multisig, 0).unwrap();
+    assert!(transaction.assert_executable(&multisig).is_err());
+
+    transaction.approve(&multisig, 2).unwrap();
+    assert!(transaction.assert_executable(&multisig).is_ok());
+
+    transaction.executed = true;
+    assert!(transaction.assert_executable(&multisig).is_err());
+    assert!(transaction.approve(&multisig, 1).is_err());
+
+    // Replacing the signers invalidates transactions proposed beforehand.
+
+    transaction.executed = false;
+    multisig.set_signers(signers, 1).unwrap();
+    assert!(transaction.assert_executable(&multisig).is_err());
+    assert!(transaction.approve(&multisig, 1).is_err());
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_finalize_pool() {
+    let reward_schedule = sealed_reward_schedule();
+
+    let mut state: State = Default::default();
+    state.venft_supply.fill(1);
+    state.venft_supply[0] = 0;
+    state.venft_supply[1] = 0;
+    state.total_rewards_claimed = 1_000 * SUBUNITS_PER_REWARD_TOKEN;
+
+    let finalizable_at =
+        ((MAX_DURATION_TO_EMIT_REWARDS_DAYS + FINALIZATION_GRACE_PERIOD_DAYS) * 24 * 60 * 60)
+            as i64;
+
+    assert!(!state.is_finalized());
+    assert!(state.treasury_surplus(u64::MAX).is_err());
+    assert!(state
+        .finalize(&reward_schedule, finalizable_at - 1)
+        .is_err());
+
+    state.finalize(&reward_schedule, finalizable_at).unwrap();
+    assert!(state.is_finalized());
+    assert!(state.finalize(&reward_schedule, finalizable_at).is_err());
+
+    // Rewards emitted on days without any veNFT's in circulation are surplus once they are no
+    // longer carried forward.
+
+    let num_tokens_emitted =
+        700_000_000 - reward_schedule.reward_for_day(0) - reward_schedule.reward_for_day(1);
+    assert_eq!(
+        state.total_rewards_emitted,
+        num_tokens_emitted * SUBUNITS_PER_REWARD_TOKEN
+    );
+    assert_eq!(
+        state.total_rewards_reclaimable,
+        state.total_rewards_carried_over
+    );
+    assert_eq!(
+        state.rewards_owed(),
+        (num_tokens_emitted - 1_000) * SUBUNITS_PER_REWARD_TOKEN
+    );
+    assert_eq!(
+        state
+            .treasury_surplus(state.rewards_owed() + 5 * SUBUNITS_PER_REWARD_TOKEN)
+            .unwrap(),
+        5 * SUBUNITS_PER_REWARD_TOKEN
+    );
+
+    // Rounding dust which stakers never get to claim is counted as owed, and stays in the
+    // treasury once everything else has been claimed and swept.
+
+    state.total_rewards_claimed = state.total_rewards_emitted - 1;
+    assert_eq!(state.rewards_owed(), 1);
+    assert_eq!(
+        state
+            .treasury_surplus(1 + 5 * SUBUNITS_PER_REWARD_TOKEN)
+            .unwrap(),
+        5 * SUBUNITS_PER_REWARD_TOKEN
+    );
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_zero_supply_rewards_policies() {
+    let reward_schedule = sealed_reward_schedule();
+    let reward_per_day: Vec<u64> = reward_schedule_in_days()
+        .iter()
+        .map(|reward| reward * SUBUNITS_PER_REWARD_TOKEN)
+        .collect();
+
+    let mut state: State = Default::default();
+    assert_eq!(
+        state.zero_supply_rewards_policy,
+        ZERO_SUPPLY_REWARDS_RECLAIMABLE
+    );
+    assert!(state
+        .set_zero_supply_rewards_policy(&reward_schedule, 0, 2)
+        .is_err());
+    state
+        .set_zero_supply_rewards_policy(&reward_schedule, 0, ZERO_SUPPLY_REWARDS_CARRY_FORWARD)
+        .unwrap();
+
+    state.venft_supply[2] = 1;
+    state.venft_supply[5] = 1;
+    state.venft_supply[7] = 1;
+
+    // Rewards of days 0 and 1 are carried forward onto day 2. Only days which have elapsed are
+    // carried forward.
+
+    state.update(&reward_schedule, 2 * DAY);
+    assert_eq!(
+        state.rewards_pending_carry_over,
+        reward_per_day[0] + reward_per_day[1]
+    );
+    assert_eq!(state.reward_for_day(&reward_schedule, 2), reward_per_day[2]);
+
+    state.update(&reward_schedule, 3 * DAY);
+    assert_eq!(state.rewards_pending_carry_over, 0);
+    assert_eq!(
+        state.reward_for_day(&reward_schedule, 2),
+        reward_per_day[0] + reward_per_day[1] + reward_per_day[2]
+    );
+
+    // Switching policies first settles elapsed days under the previous policy, such that the
+    // rewards of day 4 are carried forward. Rewards already being carried forward remain with
+    // stakers, and land on day 5 alongside the rewards of days 3 and 4.
+
+    state.update(&reward_schedule, 4 * DAY);
+    state
+        .set_zero_supply_rewards_policy(&reward_schedule, 5 * DAY, ZERO_SUPPLY_REWARDS_RECLAIMABLE)
+        .unwrap();
+    assert_eq!(
+        state.rewards_pending_carry_over,
+        reward_per_day[3] + reward_per_day[4]
+    );
+    assert_eq!(state.total_rewards_reclaimable, 0);
+
+    state.update(&reward_schedule, 6 * DAY);
+    assert_eq!(state.rewards_pending_carry_over, 0);
+    assert_eq!(
+        state.reward_for_day(&reward_schedule, 5),
+        reward_per_day[3] + reward_per_day[4] + reward_per_day[5]
+    );
+
+    // Rewards of days without veNFT's in circulation after switching are set aside to be
+    // reclaimed.
+
+    state.update(&reward_schedule, 8 * DAY);
+    assert_eq!(state.rewards_pending_carry_over, 0);
+    assert_eq!(state.reward_for_day(&reward_schedule, 7), reward_per_day[7]);
+    assert_eq!(state.total_rewards_reclaimable, reward_per_day[6]);
+    assert_eq!(
+        state.total_rewards_carried_over,
+        reward_per_day[0]
+            + reward_per_day[1]
+            + reward_per_day[3]
+            + reward_per_day[4]
+            + reward_per_day[6]
+    );
+
+    assert_eq!(state.reclaim_rewards().unwrap(), reward_per_day[6]);
+    assert_eq!(state.reclaim_rewards().unwrap(), 0);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_reward_per_venft_index() {
+    let reward_schedule = sealed_reward_schedule();
+    let reward_per_day = reward_schedule_in_days();
+
+    let mut state: State = Default::default();
+    state
+        .set_zero_supply_rewards_policy(&reward_schedule, 0, ZERO_SUPPLY_REWARDS_CARRY_FORWARD)
+        .unwrap();
+    state.venft_supply[1] = 2_000_000_000;
+    state.venft_supply[3] = 3_000_000_000;
+
+    // Days are only checkpointed once they have elapsed. Days without any veNFT's in circulation
+    // leave the index unchanged, with their rewards carried forward onto the next day which has.
 
-    pub last_updated_at: i64,
-    pub last_claimed_at: i64,
-}
+    state.update(&reward_schedule, 3 * DAY + DAY / 2);
+    assert_eq!(state.reward_per_venft(0, 1), 0);
+    assert_eq!(
+        state.reward_per_venft(0, 2),
+        ((reward_per_day[0] + reward_per_day[1]) * SUBUNITS_PER_REWARD_TOKEN) as u128
+            * REWARD_INDEX_PRECISION
+            / 2_000_000_000
+    );
+    assert_eq!(state.reward_per_venft(2, 3), 0);
+    assert_eq!(state.reward_per_venft(3, 4), 0);
 
-impl Default for Staker {
-    fn default() -> Self {
-        Self {
-            staker_id: Default::default(),
-            venft_balance: [0u64; (MAX_LOCK_DURATION_DAYS + 1) as usize],
-            num_locked_nfts: Default::default(),
-            num_rewards_claimable: Default::default(),
-            last_updated_at: Default::default(),
-            last_claimed_at: Default::default(),
-        }
-    }
-}
+    state.update(&reward_schedule, 4 * DAY);
+    assert_eq!(
+        state.reward_per_venft(2, 4),
+        ((reward_per_day[2] + reward_per_day[3]) * SUBUNITS_PER_REWARD_TOKEN) as u128
+            * REWARD_INDEX_PRECISION
+            / 3_000_000_000
+    );
 
-impl Staker {
-    pub fn update(&mut self, state: &State, current_time: i64) {
-        let days_elapsed_since_last_updated: usize =
-            days_between_timestamps(self.last_updated_at, current_time)
-                .try_into()
-                .unwrap_or(usize::MAX);
+    // A staker holding a share of the supply accrues that share of the rewards per veNFT.
 
-        if days_elapsed_since_last_updated < 1 {
-            return;
-        }
+    let mut staker: Staker = Default::default();
+    staker.accrue_rewards(1_000_000_000, state.reward_per_venft(0, 4));
+    assert_eq!(
+        staker.num_rewards_claimable,
+        ((reward_per_day[0] + reward_per_day[1]) * SUBUNITS_PER_REWARD_TOKEN) / 2
+            + ((reward_per_day[2] + reward_per_day[3]) * SUBUNITS_PER_REWARD_TOKEN) / 3
+    );
 
-        let days_elapsed_since_program_deployed: usize =
-            days_between_timestamps(state.deployed_at, self.last_updated_at)
-                .try_into()
-                .unwrap_or(usize::MAX);
-        let days_left_before_reward_emissions_end: usize = (MAX_DURATION_TO_EMIT_REWARDS_DAYS
-            as usize)
-            .saturating_sub(days_elapsed_since_program_deployed);
+    // Reading past the end of reward emissions is clamped to the last checkpoint.
 
-        let rollover_count: usize = days_elapsed_since_last_updated
-            .min(MAX_LOCK_DURATION_DAYS as usize)
-            .min(days_left_before_reward_emissions_end);
+    assert_eq!(
+        state.reward_per_venft(0, usize::MAX),
+        state.reward_per_venft(0, MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize)
+    );
+}
 
-        let last_updated_at: i64 = std::mem::replace(&mut self.last_updated_at, current_time);
-        let day_offset: usize = days_between_timestamps(state.deployed_at, last_updated_at)
-            .try_into()
-            .unwrap_or(usize::MAX);
-
-        // Rollover the amount of days that has elapsed since the last time the staker
-        // interacted with the program. Update the amount of rewards claimable by the
-        // staker.
-
-        for day in 0..rollover_count {
-            let reward_emitted: u64 = REWARD_SCHEDULE_IN_DAYS[day_offset + day];
-            let staker_venft_balance: u64 = self.venft_balance[day];
-            let total_venft_supply: u64 = match state.venft_supply[day_offset + day] {
-                0 => continue,
-                total_venft_supply => total_venft_supply,
-            };
+#[cfg(test)]
+#[test]
+pub fn test_admin_sunsets_pool() {
+    let mut state: State = Default::default();
+    assert!(!state.is_sunset());
+    assert!(state.assert_not_sunset().is_ok());
 
-            self.num_rewards_claimable = self.num_rewards_claimable.saturating_add(
-                reward_emitted.saturating_mul(staker_venft_balance) / total_venft_supply,
-            );
-        }
+    state.sunset(0).unwrap();
+    assert!(state.is_sunset());
+    assert!(state.assert_not_sunset().is_err());
 
-        self.venft_balance[0..rollover_count].fill(0);
-        self.venft_balance.rotate_left(rollover_count);
-    }
+    // A pool may only be sunset once, and remains sunset regardless of which instructions are
+    // unpaused afterwards.
 
-    /// Empty the number of reward tokens claimable by the staker, update the timestamp denoting the
-    /// the last time the staker claimed rewards, and return the number of reward tokens claimable
-    /// by the staker.
-    pub fn claim_rewards(&mut self, current_time: i64) -> u64 {
-        let num_tokens_rewarded = std::mem::replace(&mut self.num_rewards_claimable, 0);
-        self.last_claimed_at = current_time;
-        num_tokens_rewarded
-    }
+    assert!(state.sunset(1).is_err());
+    state.set_paused(0).unwrap();
+    assert!(state.assert_not_sunset().is_err());
+}
 
-    pub fn stake_nft(&mut self, state: &mut State) {
-        self.num_locked_nfts = self.num_locked_nfts.saturating_add(1);
-        state.total_num_locked_nfts = state.total_num_locked_nfts.saturating_add(1);
-    }
+#[cfg(test)]
+#[test]
+pub fn test_admin_pauses_instructions() {
+    let mut state: State = Default::default();
 
-    /// veNFT's are minted to the staker in exchange for the escrow of a NFT. The veNFT's will only
-    /// be considered to be part of the total veNFT supply starting from the next day.
-    pub fn mint_venfts(
-        &mut self,
-        state: &mut State,
-        current_time: i64,
-        lock_duration_in_days: u64,
-    ) -> Result<()> {
-        // First, we assert that the lock duration in days provided is acceptable.
+    assert!(state.assert_not_paused(PAUSE_STAKE).is_ok());
+    assert!(state.assert_not_paused(PAUSE_EXTEND).is_ok());
+    assert!(state.assert_not_paused(PAUSE_CLAIM_REWARDS).is_ok());
 
-        let days_elapsed_since_program_deployed: u64 =
-            days_between_timestamps(state.deployed_at, current_time);
-        let days_left_before_reward_emissions_end: u64 =
-            MAX_DURATION_TO_EMIT_REWARDS_DAYS.saturating_sub(days_elapsed_since_program_deployed);
+    assert!(state.set_paused(PAUSE_ALL << 1).is_err());
 
-        let min_lock_duration_allowed = MIN_LOCK_DURATION_DAYS;
-        let max_lock_duration_allowed =
-            MAX_LOCK_DURATION_DAYS.min(days_left_before_reward_emissions_end);
+    // Freeze new stakes and extensions, but keep allowing claims.
 
-        require!(
-            (min_lock_duration_allowed..=max_lock_duration_allowed)
-                .contains(&lock_duration_in_days),
-            MaxPossibleLockDurationExceeded
-        );
+    state.set_paused(PAUSE_STAKE | PAUSE_EXTEND).unwrap();
+    assert!(state.assert_not_paused(PAUSE_STAKE).is_err());
+    assert!(state.assert_not_paused(PAUSE_EXTEND).is_err());
+    assert!(state.assert_not_paused(PAUSE_CLAIM_REWARDS).is_ok());
 
-        // Second, we mint veNFT's to the staker over the lock duration in days specified. All
-        // veNFT's gets accounted for in the total veNFT supply as well.
+    state.set_paused(PAUSE_ALL).unwrap();
+    assert!(state.assert_not_paused(PAUSE_CLAIM_REWARDS).is_err());
 
-        let day_offset: usize = days_elapsed_since_program_deployed
-            .try_into()
-            .unwrap_or(usize::MAX);
+    state.set_paused(0).unwrap();
+    assert!(state.assert_not_paused(PAUSE_STAKE).is_ok());
+    assert!(state.assert_not_paused(PAUSE_EXTEND).is_ok());
+    assert!(state.assert_not_paused(PAUSE_CLAIM_REWARDS).is_ok());
+}
 
-        let mut mint_amount = lock_duration_in_days.saturating_mul(1_000_000_000);
-        let mut balance = self.venft_balance[0..].iter_mut();
-        let mut supply = state.venft_supply[day_offset..].iter_mut();
+#[cfg(test)]
+#[test]
+pub fn test_pool_config_allowed_creators() {
+    use mpl_token_metadata::state::Creator;
 
-        const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+    let mut pool_config: PoolConfig = Default::default();
 
-        let seconds_left_before_next_day: u64 = SECONDS_PER_DAY
-            .saturating_sub(current_time.try_into().unwrap_or(u64::MAX) % SECONDS_PER_DAY);
+    let verified_creator_id = Pubkey::new_unique();
+    let unverified_creator_id = Pubkey::new_unique();
 
-        let first_day_decay_rate =
-            1_000_000_000u64.saturating_mul(seconds_left_before_next_day) / SECONDS_PER_DAY;
+    let creator = |address: Pubkey, verified: bool| Creator {
+        address,
+        verified,
+        share: 100,
+    };
 
-        if first_day_decay_rate > 0 {
-            if let Some(balance) = balance.next() {
-                *balance = (*balance).saturating_add(mint_amount);
-            }
-            if let Some(supply) = supply.next() {
-                *supply = (*supply).saturating_add(mint_amount);
-            }
-            mint_amount = mint_amount.saturating_sub(first_day_decay_rate);
-        }
+    assert!(pool_config
+        .assert_allowed_creators(&[creator(verified_creator_id, true)])
+        .is_err());
 
-        for _ in 0..lock_duration_in_days {
-            if let Some(balance) = balance.next() {
-                *balance = (*balance).saturating_add(mint_amount);
-            }
-            if let Some(supply) = supply.next() {
-                *supply = (*supply).saturating_add(mint_amount);
-            }
-            mint_amount = mint_amount.saturating_sub(1_000_000_000u64);
-        }
+    pool_config
+        .add_allowed_creator(verified_creator_id, true)
+        .unwrap();
+    pool_config
+        .add_allowed_creator(unverified_creator_id, false)
+        .unwrap();
 
-        if mint_amount > 0 {
-            if let Some(balance) = balance.next() {
-                *balance = (*balance).saturating_add(mint_amount);
-            }
-            if let Some(supply) = supply.next() {
-                *supply = (*supply).saturating_add(mint_amount);
-            }
-        }
+    assert!(pool_config.assert_allowed_creators(&[]).is_err());
+    assert!(pool_config
+        .assert_allowed_creators(&[creator(verified_creator_id, true)])
+        .is_ok());
+    assert!(pool_config
+        .assert_allowed_creators(&[creator(verified_creator_id, false)])
+        .is_err());
+    assert!(pool_config
+        .assert_allowed_creators(&[creator(unverified_creator_id, false)])
+        .is_ok());
+    assert!(pool_config
+        .assert_allowed_creators(&[
+            creator(Pubkey::new_unique(), true),
+            creator(verified_creator_id, true)
+        ])
+        .is_ok());
+
+    // Re-adding a creator only updates whether or not it must be verified.
+
+    pool_config
+        .add_allowed_creator(verified_creator_id, false)
+        .unwrap();
+    assert_eq!(pool_config.allowed_creators.len(), 2);
+    assert!(pool_config
+        .assert_allowed_creators(&[creator(verified_creator_id, false)])
+        .is_ok());
 
-        Ok(())
-    }
-}
+    pool_config
+        .remove_allowed_creator(verified_creator_id)
+        .unwrap();
+    assert!(pool_config
+        .remove_allowed_creator(verified_creator_id)
+        .is_err());
+    assert!(pool_config
+        .assert_allowed_creators(&[creator(verified_creator_id, false)])
+        .is_err());
 
-pub fn days_between_timestamps(start: i64, end: i64) -> u64 {
-    if end <= start {
-        return 0;
+    while pool_config.allowed_creators.len() < MAX_NUM_ALLOWED_CREATORS {
+        pool_config
+            .add_allowed_creator(Pubkey::new_unique(), true)
+            .unwrap();
     }
-    end.saturating_sub(start)
-        .checked_div(60 * 60 * 24)
-        .unwrap_or(0i64)
-        .try_into()
-        .unwrap_or(0u64)
+    assert!(pool_config
+        .add_allowed_creator(Pubkey::new_unique(), true)
+        .is_err());
 }
 
 #[cfg(test)]
 #[test]
-pub fn test_staker_lock_duration_edge_cases() {
-    let mut state: State = Default::default();
-    let mut staker: Staker = Default::default();
+pub fn test_pool_config_nft_gating_modes() {
+    use mpl_token_metadata::state::{Collection, Creator};
 
-    // Lower bound checks.
+    let mut pool_config: PoolConfig = Default::default();
 
-    assert!(staker
-        .mint_venfts(&mut state, 0 * 24 * 60 * 60, MIN_LOCK_DURATION_DAYS - 1)
-        .is_err());
+    let creator_id = Pubkey::new_unique();
+    let collection_mint_id = Pubkey::new_unique();
 
-    assert!(staker
-        .mint_venfts(&mut state, 0 * 24 * 60 * 60, MAX_LOCK_DURATION_DAYS + 1)
+    pool_config.add_allowed_creator(creator_id, true).unwrap();
+    pool_config
+        .add_allowed_collection(collection_mint_id)
+        .unwrap();
+    pool_config
+        .add_allowed_collection(collection_mint_id)
+        .unwrap();
+    assert_eq!(pool_config.allowed_collections.len(), 1);
+
+    let creators = [Creator {
+        address: creator_id,
+        verified: true,
+        share: 100,
+    }];
+    let collection = Collection {
+        verified: true,
+        key: collection_mint_id,
+    };
+    let unverified_collection = Collection {
+        verified: false,
+        key: collection_mint_id,
+    };
+    let unexpected_collection = Collection {
+        verified: true,
+        key: Pubkey::new_unique(),
+    };
+
+    // Gated by creator.
+
+    assert_eq!(pool_config.nft_gating_mode, NftGatingMode::Creator);
+    assert!(pool_config.assert_lockable(Some(&creators), None).is_ok());
+    assert!(pool_config
+        .assert_lockable(None, Some(&collection))
         .is_err());
 
-    assert!(staker
-        .mint_venfts(
-            &mut state,
-            MAX_DURATION_TO_EMIT_REWARDS_DAYS as i64 * 24 * 60 * 60,
-            1
-        )
+    // Gated by collection.
+
+    pool_config.nft_gating_mode = NftGatingMode::Collection;
+    assert!(pool_config.assert_lockable(None, Some(&collection)).is_ok());
+    assert!(pool_config.assert_lockable(Some(&creators), None).is_err());
+    assert!(pool_config
+        .assert_lockable(Some(&creators), Some(&unverified_collection))
+        .is_err());
+    assert!(pool_config
+        .assert_lockable(Some(&creators), Some(&unexpected_collection))
         .is_err());
 
-    // Upper bound checks.
+    // Gated by both.
 
-    assert!(staker
-        .mint_venfts(
-            &mut state,
-            (MAX_DURATION_TO_EMIT_REWARDS_DAYS - MAX_LOCK_DURATION_DAYS + 1) as i64 * 24 * 60 * 60,
-            MAX_LOCK_DURATION_DAYS
-        )
+    pool_config.nft_gating_mode = NftGatingMode::CreatorAndCollection;
+    assert!(pool_config
+        .assert_lockable(Some(&creators), Some(&collection))
+        .is_ok());
+    assert!(pool_config
+        .assert_lockable(None, Some(&collection))
+        .is_err());
+    assert!(pool_config.assert_lockable(Some(&creators), None).is_err());
+
+    pool_config
+        .remove_allowed_collection(collection_mint_id)
+        .unwrap();
+    assert!(pool_config
+        .remove_allowed_collection(collection_mint_id)
+        .is_err());
+    assert!(pool_config
+        .assert_lockable(Some(&creators), Some(&collection))
         .is_err());
 }
 
+/// The reward schedule uploaded on-chain for the Solciety staking pool is shared with the web
+/// frontend.
 #[cfg(test)]
-#[test]
-pub fn test_staker_stakes_nfts() {
-    let mut state: State = Default::default();
-    let mut staker: Staker = Default::default();
+pub fn reward_schedule_in_days() -> Vec<u64> {
+    include_str!("../../../app/pages/reward_schedule.json")
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|reward| !reward.is_empty())
+        .map(|reward| reward.parse().unwrap())
+        .collect()
+}
 
-    state.update(0 * 24 * 60 * 60);
-    staker.update(&state, 0 * 24 * 60 * 60);
-    staker.stake_nft(&mut state);
+#[cfg(test)]
+pub fn sealed_reward_schedule() -> RewardSchedule {
+    let mut reward_schedule: RewardSchedule = Default::default();
+    for (i, chunk) in reward_schedule_in_days().chunks(128).enumerate() {
+        reward_schedule.write(i as u64 * 128, chunk).unwrap();
+    }
+    reward_schedule.seal(700_000_000, 1).unwrap();
+    reward_schedule
+}
 
-    staker
-        .mint_venfts(&mut state, 0 * 24 * 60 * 60, MAX_LOCK_DURATION_DAYS)
-        .unwrap();
+#[cfg(test)]
+pub const DAY: i64 = 24 * 60 * 60;
 
-    assert_eq!(
-        &state.venft_supply[0..5],
-        &[
-            182000000000,
-            181000000000,
-            180000000000,
-            179000000000,
-            178000000000,
-        ]
-    );
+/// Update the pool and then the staker, in the order every instruction touching a staker does.
+#[cfg(test)]
+pub fn update_state_and_staker(
+    state: &mut State,
+    staker: &mut Staker,
+    reward_schedule: &RewardSchedule,
+    current_time: i64,
+) {
+    state.update(reward_schedule, current_time);
+    staker.update(state, current_time);
+}
 
+/// Reward token subunits accrued according to the reward per veNFT index for holding a veNFT
+/// balance on each of the given days, computed day by day.
+#[cfg(test)]
+pub fn num_rewards_accrued_on_days(
+    state: &State,
+    balances: impl IntoIterator<Item = (u64, u64)>,
+) -> u64 {
+    let num_rewards_scaled: u128 = balances
+        .into_iter()
+        .map(|(day, balance)| {
+            balance as u128 * state.reward_per_venft(day as usize, day as usize + 1)
+        })
+        .sum();
+    (num_rewards_scaled / REWARD_INDEX_PRECISION) as u64
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_reward_schedule_sane() {
+    let reward_per_day = reward_schedule_in_days();
     assert_eq!(
-        &staker.venft_balance[0..5],
-        &[
-            182000000000,
-            181000000000,
-            180000000000,
-            179000000000,
-            178000000000,
-        ]
+        reward_per_day.len(),
+        MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize
     );
+    assert_eq!(reward_per_day.iter().sum::<u64>(), 700_000_000);
 
-    state.update(5 * 24 * 60 * 60);
-    staker.update(&state, 5 * 24 * 60 * 60);
+    let mut reward_schedule: RewardSchedule = Default::default();
 
-    assert_eq!(state.last_updated_at, 5 * 24 * 60 * 60);
-    assert_eq!(staker.last_updated_at, 5 * 24 * 60 * 60);
+    // Chunks may not leave gaps behind, nor extend past the end of reward emissions.
 
-    assert_eq!(
-        &state.venft_supply[0..5],
-        &[
-            182000000000,
-            181000000000,
-            180000000000,
-            179000000000,
-            178000000000,
-        ]
-    );
+    assert!(reward_schedule.write(1, &reward_per_day[1..2]).is_err());
+    assert!(reward_schedule
+        .write(MAX_DURATION_TO_EMIT_REWARDS_DAYS - 1, &reward_per_day[0..2])
+        .is_err());
 
-    assert_eq!(
-        &staker.venft_balance[0..5],
-        &[
-            177000000000,
-            176000000000,
-            175000000000,
-            174000000000,
-            173000000000,
-        ]
-    );
+    reward_schedule.write(0, &reward_per_day[0..1000]).unwrap();
+    assert!(reward_schedule.seal(700_000_000, 1).is_err());
 
-    assert_eq!(
-        staker.num_rewards_claimable,
-        REWARD_SCHEDULE_IN_DAYS[0..5].iter().sum()
-    );
+    reward_schedule
+        .write(1000, &reward_per_day[1000..])
+        .unwrap();
+    reward_schedule.write(0, &[0]).unwrap();
+    assert!(reward_schedule.seal(700_000_000, 1).is_err());
+
+    reward_schedule.write(0, &reward_per_day[0..1]).unwrap();
+    assert!(!reward_schedule.is_sealed());
+    reward_schedule.seal(700_000_000, 1).unwrap();
+    assert!(reward_schedule.is_sealed());
+
+    assert!(reward_schedule.write(0, &[0]).is_err());
+    assert!(reward_schedule.seal(700_000_000, 1).is_err());
+    assert_eq!(&reward_schedule.reward_per_day[..], &reward_per_day[..]);
 }
 
 #[cfg(test)]
 #[test]
-pub fn test_staker_stakes_nfts_at_end() {
-    let mut state: State = Default::default();
-    let mut staker: Staker = Default::default();
+pub fn test_emission_curves() {
+    let linear_decay = EmissionCurve {
+        kind: EMISSION_CURVE_LINEAR_DECAY,
+        total_reward_emission: 700_000_000,
+        num_days: MAX_DURATION_TO_EMIT_REWARDS_DAYS - 1,
+        ..Default::default()
+    };
 
-    state.update(1457 * 24 * 60 * 60);
-    staker.update(&state, 1457 * 24 * 60 * 60);
-    staker.stake_nft(&mut state);
-    staker
-        .mint_venfts(&mut state, 1457 * 24 * 60 * 60, 4)
-        .unwrap();
+    // Linear decay follows the reward schedule of the Solciety staking pool to the token.
 
-    assert_eq!(
-        &state.venft_supply[1457..],
-        &[4000000000, 3000000000, 2000000000, 1000000000]
-    );
+    for (day, reward) in reward_schedule_in_days().into_iter().enumerate() {
+        let difference = (linear_decay.reward_for_day(day as u64) as i64 - reward as i64).abs();
+        assert!(difference <= 2, "day {} is off by {}", day, difference);
+    }
 
+    let mut reward_schedule: RewardSchedule = Default::default();
+    assert!(reward_schedule
+        .set_emission_curve(EmissionCurve {
+            kind: EMISSION_CURVE_STEPPED_HALVING + 1,
+            ..linear_decay
+        })
+        .is_err());
+    assert!(reward_schedule
+        .set_emission_curve(EmissionCurve {
+            num_days: MAX_DURATION_TO_EMIT_REWARDS_DAYS + 1,
+            ..linear_decay
+        })
+        .is_err());
+    assert!(reward_schedule
+        .set_emission_curve(EmissionCurve {
+            kind: EMISSION_CURVE_EXPONENTIAL_DECAY,
+            decay_rate_bps: 10_000,
+            ..linear_decay
+        })
+        .is_err());
+    assert!(reward_schedule
+        .set_emission_curve(EmissionCurve {
+            kind: EMISSION_CURVE_STEPPED_HALVING,
+            halving_interval_in_days: 0,
+            ..linear_decay
+        })
+        .is_err());
+
+    reward_schedule.set_emission_curve(linear_decay).unwrap();
+    assert!(reward_schedule.write(0, &[1]).is_err());
+    assert!(reward_schedule.seal(700_000_001, 1).is_err());
+    reward_schedule.seal(700_000_000, 1).unwrap();
+    assert_eq!(reward_schedule.reward_for_day(0), 958_575);
     assert_eq!(
-        &staker.venft_balance[0..4],
-        &[4000000000, 3000000000, 2000000000, 1000000000]
+        reward_schedule.reward_for_day(MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize - 1),
+        0
     );
 
-    state.update(1600 * 24 * 60 * 60);
-    staker.update(&state, 1600 * 24 * 60 * 60);
+    // Emissions of exponential decay and stepped halving curves decay.
 
-    assert_eq!(state.last_updated_at, 1600 * 24 * 60 * 60);
-    assert_eq!(staker.last_updated_at, 1600 * 24 * 60 * 60);
+    let exponential_decay = EmissionCurve {
+        kind: EMISSION_CURVE_EXPONENTIAL_DECAY,
+        decay_rate_bps: 10,
+        ..linear_decay
+    };
+    assert!(exponential_decay.reward_for_day(0) > exponential_decay.reward_for_day(1));
 
+    let stepped_halving = EmissionCurve {
+        kind: EMISSION_CURVE_STEPPED_HALVING,
+        halving_interval_in_days: 365,
+        ..linear_decay
+    };
     assert_eq!(
-        &state.venft_supply[1457..],
-        &[4000000000, 3000000000, 2000000000, 1000000000]
+        stepped_halving.reward_for_day(0),
+        stepped_halving.reward_for_day(364)
     );
-
-    assert!(staker.venft_balance.iter().all(|balance| *balance == 0));
-
     assert_eq!(
-        staker.num_rewards_claimable,
-        REWARD_SCHEDULE_IN_DAYS[REWARD_SCHEDULE_IN_DAYS.len() - 4..]
-            .iter()
-            .sum()
+        stepped_halving.reward_for_day(0) / 2,
+        stepped_halving.reward_for_day(365)
     );
 }
 
 #[cfg(test)]
-#[test]
-pub fn test_reward_schedule_sane() {
-    assert_eq!(REWARD_SCHEDULE_IN_DAYS.iter().sum::<u64>(), 700_000_000);
+#[quickcheck]
+pub fn test_check_emission_curves_emit_total(
+    kind: u64,
+    total_reward_emission: u64,
+    num_days: u64,
+    decay_rate_bps: u64,
+    halving_interval_in_days: u64,
+) -> bool {
+    let emission_curve = EmissionCurve {
+        kind: EMISSION_CURVE_CONSTANT + kind % 4,
+        total_reward_emission: total_reward_emission % 10_000_000_000_000,
+        num_days: 1 + num_days % MAX_DURATION_TO_EMIT_REWARDS_DAYS,
+        decay_rate_bps: 1 + decay_rate_bps % 9_999,
+        halving_interval_in_days: 1 + halving_interval_in_days % MAX_DURATION_TO_EMIT_REWARDS_DAYS,
+    };
+    emission_curve.validate().unwrap();
+
+    let total: u64 = (0..MAX_DURATION_TO_EMIT_REWARDS_DAYS)
+        .map(|day| emission_curve.reward_for_day(day))
+        .sum();
+
+    total == emission_curve.total_reward_emission
 }
 
 #[cfg(test)]
@@ -831,7 +6022,7 @@ pub fn test_check_locked_nft_max_num_days_may_be_extended(
         ..Default::default()
     };
 
-    locked_nft.max_num_days_may_be_extended(current_time as i64);
+    locked_nft.max_num_days_may_be_extended(current_time as i64, MAX_LOCK_DURATION_DAYS);
 }
 
 #[cfg(test)]
@@ -853,7 +6044,8 @@ pub fn test_check_locked_nft_extend_lock_duration(
     };
 
     let current_time = locked_at as i64 + (days_locked_so_far % lock_duration_in_days) as i64;
-    let max_num_days_extendable = locked_nft.max_num_days_may_be_extended(current_time);
+    let max_num_days_extendable =
+        locked_nft.max_num_days_may_be_extended(current_time, MAX_LOCK_DURATION_DAYS);
 
     if max_num_days_extendable > 0 {
         locked_nft.extend_lock_duration(
@@ -872,7 +6064,7 @@ pub fn test_check_locked_nft_extend_lock_duration(
 
 #[cfg(test)]
 #[quickcheck]
-#[allow(unused_comparisons)]
+#[allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
 pub fn test_check_days_between_timestamps(start: i64, end: i64) -> bool {
     if end <= start {
         days_between_timestamps(start, end) == 0
@@ -886,6 +6078,87 @@ enum AccountLoaderStatus<'info, T: ZeroCopy + Owner> {
     Initialized(RefMut<'info, T>),
 }
 
+/// Load a staker for staking, initializing it should the staker stake a NFT for the first time.
+fn load_or_init_staker<'a>(
+    loader: &'a AccountLoader<Staker>,
+    staker_id: Pubkey,
+    bump: u8,
+    current_time: i64,
+) -> Result<RefMut<'a, Staker>> {
+    match load_maybe_init_mut(loader)? {
+        AccountLoaderStatus::Initialized(staker) => {
+            require!(staker_id == staker.staker_id, StakerIdMismatch);
+            Ok(staker)
+        }
+        AccountLoaderStatus::Uninitialized(mut staker) => {
+            staker.staker_id = staker_id;
+            staker.bump = bump;
+            staker.venft_balance = 0;
+            staker.venft_balance_change = 0;
+            staker.venft_balance_change_deltas.fill(0);
+            staker.venft_breakpoints.fill(0);
+            staker.perpetual_venft_balance = 0;
+            staker.num_locked_nfts = 0;
+            staker.num_rewards_claimable = 0;
+            staker.reward_remainder = 0;
+            staker.num_rewards_forfeit_owed = 0;
+            staker.last_updated_at = current_time;
+            staker.last_claimed_at = 0;
+            Ok(staker)
+        }
+    }
+}
+
+/// Create an account at a program derived address the same way Anchor creates accounts marked
+/// `init`, for accounts which are supplied through remaining accounts.
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    use anchor_lang::solana_program::{program, system_instruction};
+
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        program::invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                account.key,
+                rent_exempt_lamports,
+                space as u64,
+                owner,
+            ),
+            &[payer.clone(), account.clone(), system_program.clone()],
+            &[signer_seeds],
+        )?;
+        return Ok(());
+    }
+
+    let lamports_needed = rent_exempt_lamports.saturating_sub(account.lamports());
+    if lamports_needed > 0 {
+        program::invoke(
+            &system_instruction::transfer(payer.key, account.key, lamports_needed),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    program::invoke_signed(
+        &system_instruction::allocate(account.key, space as u64),
+        &[account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+    program::invoke_signed(
+        &system_instruction::assign(account.key, owner),
+        &[account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+
+    Ok(())
+}
+
 fn load_maybe_init_mut<'a, T: ZeroCopy + Owner>(
     loader: &'a AccountLoader<T>,
 ) -> Result<AccountLoaderStatus<'a, T>> {
@@ -916,127 +6189,3 @@ fn load_maybe_init_mut<'a, T: ZeroCopy + Owner>(
 
     Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into())
 }
-
-pub const REWARD_SCHEDULE_IN_DAYS: [u64; MAX_DURATION_TO_EMIT_REWARDS_DAYS as usize] = [
-    958575, 957919, 957262, 956605, 955948, 955292, 954635, 953978, 953321, 952664, 952008, 951351,
-    950694, 950037, 949381, 948724, 948067, 947410, 946753, 946097, 945440, 944783, 944126, 943469,
-    942813, 942156, 941499, 940842, 940186, 939529, 938872, 938215, 937558, 936902, 936245, 935588,
-    934931, 934275, 933618, 932961, 932304, 931647, 930991, 930334, 929677, 929020, 928363, 927707,
-    927050, 926393, 925736, 925080, 924423, 923766, 923109, 922452, 921796, 921139, 920482, 919825,
-    919168, 918512, 917855, 917198, 916541, 915885, 915228, 914571, 913914, 913257, 912601, 911944,
-    911287, 910630, 909974, 909317, 908660, 908003, 907346, 906690, 906033, 905376, 904719, 904062,
-    903406, 902749, 902092, 901435, 900779, 900122, 899465, 898808, 898151, 897495, 896838, 896181,
-    895524, 894867, 894211, 893554, 892897, 892240, 891584, 890927, 890270, 889613, 888956, 888300,
-    887643, 886986, 886329, 885673, 885016, 884359, 883702, 883045, 882389, 881732, 881075, 880418,
-    879761, 879105, 878448, 877791, 877134, 876478, 875821, 875164, 874507, 873850, 873194, 872537,
-    871880, 871223, 870566, 869910, 869253, 868596, 867939, 867283, 866626, 865969, 865312, 864655,
-    863999, 863342, 862685, 862028, 861372, 860715, 860058, 859401, 858744, 858088, 857431, 856774,
-    856117, 855460, 854804, 854147, 853490, 852833, 852177, 851520, 850863, 850206, 849549, 848893,
-    848236, 847579, 846922, 846265, 845609, 844952, 844295, 843638, 842982, 842325, 841668, 841011,
-    840354, 839698, 839041, 838384, 837727, 837071, 836414, 835757, 835100, 834443, 833787, 833130,
-    832473, 831816, 831159, 830503, 829846, 829189, 828532, 827876, 827219, 826562, 825905, 825248,
-    824592, 823935, 823278, 822621, 821965, 821308, 820651, 819994, 819337, 818681, 818024, 817367,
-    816710, 816053, 815397, 814740, 814083, 813426, 812770, 812113, 811456, 810799, 810142, 809486,
-    808829, 808172, 807515, 806858, 806202, 805545, 804888, 804231, 803575, 802918, 802261, 801604,
-    800947, 800291, 799634, 798977, 798320, 797664, 797007, 796350, 795693, 795036, 794380, 793723,
-    793066, 792409, 791752, 791096, 790439, 789782, 789125, 788469, 787812, 787155, 786498, 785841,
-    785185, 784528, 783871, 783214, 782557, 781901, 781244, 780587, 779930, 779274, 778617, 777960,
-    777303, 776646, 775990, 775333, 774676, 774019, 773363, 772706, 772049, 771392, 770735, 770079,
-    769422, 768765, 768108, 767451, 766795, 766138, 765481, 764824, 764168, 763511, 762854, 762197,
-    761540, 760884, 760227, 759570, 758913, 758256, 757600, 756943, 756286, 755629, 754973, 754316,
-    753659, 753002, 752345, 751689, 751032, 750375, 749718, 749062, 748405, 747748, 747091, 746434,
-    745778, 745121, 744464, 743807, 743150, 742494, 741837, 741180, 740523, 739867, 739210, 738553,
-    737896, 737239, 736583, 735926, 735269, 734612, 733955, 733299, 732642, 731985, 731328, 730672,
-    730015, 729358, 728701, 728044, 727388, 726731, 726074, 725417, 724761, 724104, 723447, 722790,
-    722133, 721477, 720820, 720163, 719506, 718849, 718193, 717536, 716879, 716222, 715566, 714909,
-    714252, 713595, 712938, 712282, 711625, 710968, 710311, 709655, 708998, 708341, 707684, 707027,
-    706371, 705714, 705057, 704400, 703743, 703087, 702430, 701773, 701116, 700460, 699803, 699146,
-    698489, 697832, 697176, 696519, 695862, 695205, 694548, 693892, 693235, 692578, 691921, 691265,
-    690608, 689951, 689294, 688637, 687981, 687324, 686667, 686010, 685354, 684697, 684040, 683383,
-    682726, 682070, 681413, 680756, 680099, 679442, 678786, 678129, 677472, 676815, 676159, 675502,
-    674845, 674188, 673531, 672875, 672218, 671561, 670904, 670247, 669591, 668934, 668277, 667620,
-    666964, 666307, 665650, 664993, 664336, 663680, 663023, 662366, 661709, 661053, 660396, 659739,
-    659082, 658425, 657769, 657112, 656455, 655798, 655141, 654485, 653828, 653171, 652514, 651858,
-    651201, 650544, 649887, 649230, 648574, 647917, 647260, 646603, 645946, 645290, 644633, 643976,
-    643319, 642663, 642006, 641349, 640692, 640035, 639379, 638722, 638065, 637408, 636752, 636095,
-    635438, 634781, 634124, 633468, 632811, 632154, 631497, 630840, 630184, 629527, 628870, 628213,
-    627557, 626900, 626243, 625586, 624929, 624273, 623616, 622959, 622302, 621645, 620989, 620332,
-    619675, 619018, 618362, 617705, 617048, 616391, 615734, 615078, 614421, 613764, 613107, 612451,
-    611794, 611137, 610480, 609823, 609167, 608510, 607853, 607196, 606539, 605883, 605226, 604569,
-    603912, 603256, 602599, 601942, 601285, 600628, 599972, 599315, 598658, 598001, 597344, 596688,
-    596031, 595374, 594717, 594061, 593404, 592747, 592090, 591433, 590777, 590120, 589463, 588806,
-    588150, 587493, 586836, 586179, 585522, 584866, 584209, 583552, 582895, 582238, 581582, 580925,
-    580268, 579611, 578955, 578298, 577641, 576984, 576327, 575671, 575014, 574357, 573700, 573044,
-    572387, 571730, 571073, 570416, 569760, 569103, 568446, 567789, 567132, 566476, 565819, 565162,
-    564505, 563849, 563192, 562535, 561878, 561221, 560565, 559908, 559251, 558594, 557937, 557281,
-    556624, 555967, 555310, 554654, 553997, 553340, 552683, 552026, 551370, 550713, 550056, 549399,
-    548743, 548086, 547429, 546772, 546115, 545459, 544802, 544145, 543488, 542831, 542175, 541518,
-    540861, 540204, 539548, 538891, 538234, 537577, 536920, 536264, 535607, 534950, 534293, 533636,
-    532980, 532323, 531666, 531009, 530353, 529696, 529039, 528382, 527725, 527069, 526412, 525755,
-    525098, 524442, 523785, 523128, 522471, 521814, 521158, 520501, 519844, 519187, 518530, 517874,
-    517217, 516560, 515903, 515247, 514590, 513933, 513276, 512619, 511963, 511306, 510649, 509992,
-    509335, 508679, 508022, 507365, 506708, 506052, 505395, 504738, 504081, 503424, 502768, 502111,
-    501454, 500797, 500141, 499484, 498827, 498170, 497513, 496857, 496200, 495543, 494886, 494229,
-    493573, 492916, 492259, 491602, 490946, 490289, 489632, 488975, 488318, 487662, 487005, 486348,
-    485691, 485034, 484378, 483721, 483064, 482407, 481751, 481094, 480437, 479780, 479123, 478467,
-    477810, 477153, 476496, 475840, 475183, 474526, 473869, 473212, 472556, 471899, 471242, 470585,
-    469928, 469272, 468615, 467958, 467301, 466645, 465988, 465331, 464674, 464017, 463361, 462704,
-    462047, 461390, 460734, 460077, 459420, 458763, 458106, 457450, 456793, 456136, 455479, 454822,
-    454166, 453509, 452852, 452195, 451539, 450882, 450225, 449568, 448911, 448255, 447598, 446941,
-    446284, 445627, 444971, 444314, 443657, 443000, 442344, 441687, 441030, 440373, 439716, 439060,
-    438403, 437746, 437089, 436433, 435776, 435119, 434462, 433805, 433149, 432492, 431835, 431178,
-    430521, 429865, 429208, 428551, 427894, 427238, 426581, 425924, 425267, 424610, 423954, 423297,
-    422640, 421983, 421326, 420670, 420013, 419356, 418699, 418043, 417386, 416729, 416072, 415415,
-    414759, 414102, 413445, 412788, 412132, 411475, 410818, 410161, 409504, 408848, 408191, 407534,
-    406877, 406220, 405564, 404907, 404250, 403593, 402937, 402280, 401623, 400966, 400309, 399653,
-    398996, 398339, 397682, 397025, 396369, 395712, 395055, 394398, 393742, 393085, 392428, 391771,
-    391114, 390458, 389801, 389144, 388487, 387831, 387174, 386517, 385860, 385203, 384547, 383890,
-    383233, 382576, 381919, 381263, 380606, 379949, 379292, 378636, 377979, 377322, 376665, 376008,
-    375352, 374695, 374038, 373381, 372724, 372068, 371411, 370754, 370097, 369441, 368784, 368127,
-    367470, 366813, 366157, 365500, 364843, 364186, 363530, 362873, 362216, 361559, 360902, 360246,
-    359589, 358932, 358275, 357618, 356962, 356305, 355648, 354991, 354335, 353678, 353021, 352364,
-    351707, 351051, 350394, 349737, 349080, 348424, 347767, 347110, 346453, 345796, 345140, 344483,
-    343826, 343169, 342512, 341856, 341199, 340542, 339885, 339229, 338572, 337915, 337258, 336601,
-    335945, 335288, 334631, 333974, 333317, 332661, 332004, 331347, 330690, 330034, 329377, 328720,
-    328063, 327406, 326750, 326093, 325436, 324779, 324123, 323466, 322809, 322152, 321495, 320839,
-    320182, 319525, 318868, 318211, 317555, 316898, 316241, 315584, 314928, 314271, 313614, 312957,
-    312300, 311644, 310987, 310330, 309673, 309016, 308360, 307703, 307046, 306389, 305733, 305076,
-    304419, 303762, 303105, 302449, 301792, 301135, 300478, 299822, 299165, 298508, 297851, 297194,
-    296538, 295881, 295224, 294567, 293910, 293254, 292597, 291940, 291283, 290627, 289970, 289313,
-    288656, 287999, 287343, 286686, 286029, 285372, 284715, 284059, 283402, 282745, 282088, 281432,
-    280775, 280118, 279461, 278804, 278148, 277491, 276834, 276177, 275521, 274864, 274207, 273550,
-    272893, 272237, 271580, 270923, 270266, 269609, 268953, 268296, 267639, 266982, 266326, 265669,
-    265012, 264355, 263698, 263042, 262385, 261728, 261071, 260414, 259758, 259101, 258444, 257787,
-    257131, 256474, 255817, 255160, 254503, 253847, 253190, 252533, 251876, 251220, 250563, 249906,
-    249249, 248592, 247936, 247279, 246622, 245965, 245308, 244652, 243995, 243338, 242681, 242025,
-    241368, 240711, 240054, 239397, 238741, 238084, 237427, 236770, 236113, 235457, 234800, 234143,
-    233486, 232830, 232173, 231516, 230859, 230202, 229546, 228889, 228232, 227575, 226919, 226262,
-    225605, 224948, 224291, 223635, 222978, 222321, 221664, 221007, 220351, 219694, 219037, 218380,
-    217724, 217067, 216410, 215753, 215096, 214440, 213783, 213126, 212469, 211813, 211156, 210499,
-    209842, 209186, 208530, 207873, 207216, 206559, 205902, 205246, 204589, 203932, 203275, 202619,
-    201962, 201305, 200648, 199991, 199335, 198678, 198021, 197364, 196707, 196051, 195394, 194737,
-    194080, 193424, 192767, 192110, 191453, 190796, 190140, 189483, 188826, 188169, 187513, 186856,
-    186199, 185542, 184885, 184229, 183572, 182915, 182258, 181601, 180945, 180288, 179631, 178974,
-    178318, 177661, 177004, 176347, 175690, 175034, 174377, 173720, 173063, 172406, 171750, 171093,
-    170436, 169779, 169123, 168466, 167809, 167152, 166495, 165839, 165182, 164525, 163868, 163212,
-    162555, 161898, 161241, 160584, 159928, 159271, 158614, 157957, 157300, 156644, 155987, 155330,
-    154673, 154017, 153360, 152703, 152046, 151389, 150733, 150076, 149419, 148762, 148105, 147449,
-    146792, 146135, 145478, 144822, 144165, 143508, 142851, 142194, 141538, 140881, 140224, 139567,
-    138911, 138254, 137597, 136940, 136283, 135627, 134970, 134313, 133656, 132999, 132343, 131686,
-    131029, 130372, 129716, 129059, 128402, 127745, 127088, 126432, 125775, 125118, 124461, 123804,
-    123148, 122491, 121834, 121177, 120521, 119864, 119207, 118550, 117893, 117237, 116580, 115923,
-    115266, 114610, 113953, 113296, 112639, 111982, 111326, 110669, 110012, 109355, 108698, 108042,
-    107385, 106728, 106071, 105415, 104758, 104101, 103444, 102787, 102131, 101474, 100817, 100160,
-    99504, 98847, 98190, 97533, 96876, 96220, 95563, 94906, 94249, 93592, 92936, 92279, 91622,
-    90965, 90309, 89652, 88995, 88338, 87681, 87025, 86368, 85711, 85054, 84397, 83741, 83084,
-    82427, 81770, 81114, 80457, 79800, 79143, 78486, 77830, 77173, 76516, 75859, 75203, 74546,
-    73889, 73232, 72575, 71919, 71262, 70605, 69948, 69291, 68635, 67978, 67321, 66664, 66008,
-    65351, 64694, 64037, 63380, 62724, 62067, 61410, 60753, 60096, 59440, 58783, 58126, 57469,
-    56813, 56156, 55499, 54842, 54185, 53529, 52872, 52215, 51558, 50902, 50245, 49588, 48931,
-    48274, 47618, 46961, 46304, 45647, 44990, 44334, 43677, 43020, 42363, 41707, 41050, 40393,
-    39736, 39079, 38423, 37766, 37109, 36452, 35795, 35139, 34482, 33825, 33168, 32512, 31855,
-    31198, 30541, 29884, 29228, 28571, 27914, 27257, 26601, 25944, 25287, 24630, 23973, 23317,
-    22660, 22003, 21346, 20689, 20033, 19376, 18719, 18062, 17406, 16749, 16092, 15435, 14778,
-    14122, 13465, 12808, 12151, 11494, 10838, 10181, 9524, 8867, 8211, 7554, 6897, 6240, 5583,
-    4927, 4270, 3613, 2956, 2300, 1643, 986, 329, 0,
-];